@@ -1,10 +1,36 @@
 use std::fmt;
 
 use serde::{Deserialize, Serialize};
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 /// Unique identifier of a component. It should be reverse-DNS name.
 pub struct AppId(pub String);
 
+impl AppId {
+    /// Returns the namespace of the id, everything before its last segment.
+    /// For example `org.gnome` for `org.gnome.gedit`.
+    ///
+    /// Returns `None` if the id has less than two segments.
+    pub fn namespace(&self) -> Option<&str> {
+        self.0.rsplit_once('.').map(|(namespace, _)| namespace)
+    }
+
+    /// Returns the top level domain of the id, its first segment. For
+    /// example `org` for `org.gnome.gedit`.
+    ///
+    /// Returns `None` if the id has less than two segments.
+    pub fn tld(&self) -> Option<&str> {
+        self.0.split_once('.').map(|(tld, _)| tld)
+    }
+
+    /// Whether the id is a reverse-DNS name, made of at least two non-empty
+    /// segments separated by dots and without any whitespace.
+    pub fn is_reverse_dns(&self) -> bool {
+        self.0.contains('.')
+            && !self.0.contains(char::is_whitespace)
+            && self.0.split('.').all(|segment| !segment.is_empty())
+    }
+}
+
 impl From<&str> for AppId {
     fn from(id: &str) -> Self {
         Self(id.to_string())
@@ -22,3 +48,43 @@ impl fmt::Display for AppId {
         f.write_str(&self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::AppId;
+
+    #[test]
+    fn app_id_set() {
+        let mut ids = HashSet::new();
+        assert!(ids.insert(AppId::from("org.gnome.design.Contrast")));
+        assert!(ids.insert(AppId::from("org.gnome.design.Palette")));
+        assert!(!ids.insert(AppId::from("org.gnome.design.Contrast")));
+        assert_eq!(ids.len(), 2);
+    }
+
+    #[test]
+    fn app_id_segments() {
+        let id = AppId::from("org.gnome.design.Contrast");
+        assert_eq!(id.namespace(), Some("org.gnome.design"));
+        assert_eq!(id.tld(), Some("org"));
+
+        let id = AppId::from("org.gnome");
+        assert_eq!(id.namespace(), Some("org"));
+        assert_eq!(id.tld(), Some("org"));
+
+        let id = AppId::from("firefox");
+        assert_eq!(id.namespace(), None);
+        assert_eq!(id.tld(), None);
+    }
+
+    #[test]
+    fn app_id_reverse_dns() {
+        assert!(AppId::from("org.gnome.design.Contrast").is_reverse_dns());
+        assert!(AppId::from("gnome-power-statistics.desktop").is_reverse_dns());
+        assert!(!AppId::from("firefox").is_reverse_dns());
+        assert!(!AppId::from("org..gnome").is_reverse_dns());
+        assert!(!AppId::from("org.gnome foo").is_reverse_dns());
+    }
+}
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use super::enums::{ColorKind, ColorScheme};
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+/// Defines the branding of the component, like the accent colors software
+/// centers can use when presenting it.
+/// See [\<branding\/\>](https://www.freedesktop.org/software/appstream/docs/chap-Metadata.html#tag-branding).
+pub struct Branding {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// The brand colors.
+    pub colors: Vec<BrandingColor>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+/// A brand color of the component.
+pub struct BrandingColor {
+    #[serde(rename = "type")]
+    /// The color type.
+    pub kind: ColorKind,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The color scheme the color is meant for, `None` if it can be used with
+    /// any.
+    pub scheme_preference: Option<ColorScheme>,
+
+    /// The color as a `#rgb` or `#rrggbb` hexadecimal value.
+    pub value: String,
+}
+
+impl BrandingColor {
+    /// Whether `value` is a `#rgb` or `#rrggbb` hexadecimal color.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The color to check.
+    pub fn is_valid_hex(value: &str) -> bool {
+        value.strip_prefix('#').is_some_and(|hex| {
+            matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryFrom, error::Error};
+
+    use super::*;
+    use crate::ParseError;
+
+    #[test]
+    fn branding_colors() -> Result<(), Box<dyn Error>> {
+        let xml = r"
+            <branding>
+                <color type='primary' scheme_preference='light'>#ffffff</color>
+                <color type='primary' scheme_preference='dark'>#fff</color>
+                <color type='primary'>#1c71d8</color>
+            </branding>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let b1 = Branding::try_from(&element)?;
+
+        let b2 = Branding {
+            colors: vec![
+                BrandingColor {
+                    kind: ColorKind::Primary,
+                    scheme_preference: Some(ColorScheme::Light),
+                    value: "#ffffff".into(),
+                },
+                BrandingColor {
+                    kind: ColorKind::Primary,
+                    scheme_preference: Some(ColorScheme::Dark),
+                    value: "#fff".into(),
+                },
+                BrandingColor {
+                    kind: ColorKind::Primary,
+                    scheme_preference: None,
+                    value: "#1c71d8".into(),
+                },
+            ],
+        };
+        assert_eq!(b1, b2);
+        Ok(())
+    }
+
+    #[test]
+    fn branding_invalid_color() -> Result<(), Box<dyn Error>> {
+        for value in ["#xyz", "fff", "#ffff"] {
+            let xml = format!(
+                "<branding><color type='primary'>{}</color></branding>",
+                value
+            );
+            let element = xmltree::Element::parse(xml.as_bytes())?;
+            let error = Branding::try_from(&element).unwrap_err();
+            assert!(matches!(error, ParseError::InvalidValue(v, _, _) if v == value));
+        }
+        Ok(())
+    }
+}
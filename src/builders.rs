@@ -1,12 +1,12 @@
 use std::collections::HashMap;
 
-use chrono::{DateTime, Utc};
 use url::Url;
 
 use super::{
-    collection::Collection, component::Component, enums::*, AppId, Artifact, ContentRating, Image,
-    Language, License, MarkupTranslatableString, Release, Requirement, Screenshot,
-    TranslatableList, TranslatableString, Video,
+    collection::Collection, component::Component, enums::*, AppId, Artifact, Branding,
+    ContentRating, DateTime, DisplayLength, DisplayLengthValue, Image, Language, License,
+    MarkupTranslatableString, Rel, Release, Requirement, Screenshot, Side, TranslatableList,
+    TranslatableString, Video,
 };
 
 #[derive(Default, Debug)]
@@ -191,6 +191,10 @@ pub struct ComponentBuilder {
     pub bundles: Vec<Bundle>,
     /// Metainformation that describes the various releases.
     pub releases: Vec<Release>,
+    /// The order in which the releases are listed.
+    pub release_order: ReleaseOrder,
+    /// The URL of the external releases file.
+    pub releases_url: Option<Url>,
     /// The languages supported by the component.
     pub languages: Vec<Language>,
     /// The MIME types the component supports.
@@ -199,8 +203,12 @@ pub struct ComponentBuilder {
     pub kudos: Vec<Kudo>,
     /// A list of keywords, to help the user find the component easily.
     pub keywords: Option<TranslatableList>,
+    /// The keywords marked as machine translated.
+    pub machine_translated_keywords: Option<TranslatableList>,
     /// Specifies the age rating of the component.
     pub content_rating: Option<ContentRating>,
+    /// The branding of the component.
+    pub branding: Option<Branding>,
     /// Public interfaces the component provides.
     pub provides: Vec<Provide>,
     /// Specifies the translation domains.
@@ -211,6 +219,8 @@ pub struct ComponentBuilder {
     pub suggestions: Vec<AppId>,
     /// Custom metadata
     pub metadata: HashMap<String, Option<String>>,
+    /// How the component merges with the one sharing its id.
+    pub merge: MergeKind,
     /// denotes a supported requirement, this is a weaker statement that
     /// `recommends`.
     pub supports: Vec<Requirement>,
@@ -243,6 +253,13 @@ impl ComponentBuilder {
         self
     }
 
+    /// Sets the branding of the component.
+    #[must_use]
+    pub fn branding(mut self, branding: Branding) -> Self {
+        self.branding = Some(branding);
+        self
+    }
+
     /// Sets the component type.
     #[must_use]
     pub fn kind(mut self, kind: ComponentKind) -> Self {
@@ -250,6 +267,13 @@ impl ComponentBuilder {
         self
     }
 
+    /// Sets how the component merges with the one sharing its id.
+    #[must_use]
+    pub fn merge(mut self, merge: MergeKind) -> Self {
+        self.merge = merge;
+        self
+    }
+
     /// Sets the developer name.
     #[must_use]
     pub fn developer_name(mut self, developer_name: TranslatableString) -> Self {
@@ -291,6 +315,16 @@ impl ComponentBuilder {
         self
     }
 
+    /// Sets the keywords marked as machine translated, they are expected to
+    /// be part of the keywords as well.
+    #[must_use]
+    pub fn machine_translated_keywords(mut self, keywords: TranslatableList) -> Self {
+        if !keywords.is_empty() {
+            self.machine_translated_keywords = Some(keywords);
+        }
+        self
+    }
+
     /// Sets the keywords.
     #[must_use]
     pub fn keywords(mut self, keywords: TranslatableList) -> Self {
@@ -399,6 +433,20 @@ impl ComponentBuilder {
         self
     }
 
+    /// Sets the order in which the releases are listed.
+    #[must_use]
+    pub fn release_order(mut self, release_order: ReleaseOrder) -> Self {
+        self.release_order = release_order;
+        self
+    }
+
+    /// Sets the URL of the external releases file.
+    #[must_use]
+    pub fn releases_url(mut self, releases_url: Url) -> Self {
+        self.releases_url = Some(releases_url);
+        self
+    }
+
     /// Adds a launchable to the component.
     #[must_use]
     pub fn launchable(mut self, launchable: Launchable) -> Self {
@@ -462,6 +510,34 @@ impl ComponentBuilder {
         self
     }
 
+    /// Adds a display length requirement to the component, measured on the
+    /// shortest side of the display.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::{builders::ComponentBuilder, DisplayLengthValue, Rel, TranslatableString};
+    ///
+    /// let component = ComponentBuilder::default()
+    ///     .id("org.gnome.design.Contrast".into())
+    ///     .name(TranslatableString::with_default("Contrast"))
+    ///     .requires_display_length(DisplayLengthValue::Small, Rel::Ge)
+    ///     .build();
+    ///
+    /// let display_length = component.required_display_length().unwrap();
+    /// assert_eq!(display_length.value, DisplayLengthValue::Small);
+    /// assert_eq!(display_length.compare, Rel::Ge);
+    /// ```
+    #[must_use]
+    pub fn requires_display_length(mut self, value: DisplayLengthValue, compare: Rel) -> Self {
+        self.requires
+            .push(Requirement::DisplayLength(DisplayLength {
+                compare,
+                value,
+                side: Side::default(),
+            }));
+        self
+    }
+
     /// Constructs a `Component`.
     #[must_use]
     pub fn build(self) -> Component {
@@ -489,16 +565,84 @@ impl ComponentBuilder {
             pkgname: self.pkgname,
             bundles: self.bundles,
             releases: self.releases,
+            release_order: self.release_order,
+            releases_url: self.releases_url,
             languages: self.languages,
             mimetypes: self.mimetypes,
             kudos: self.kudos,
             keywords: self.keywords,
+            machine_translated_keywords: self.machine_translated_keywords,
             content_rating: self.content_rating,
+            branding: self.branding,
             provides: self.provides,
             translations: self.translations,
             source_pkgname: self.source_pkgname,
             suggestions: self.suggestions,
             metadata: self.metadata,
+            merge: self.merge,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// A helper to build a `DisplayLength`.
+///
+/// # Example
+///
+/// ```
+/// use appstream::{builders::DisplayLengthBuilder, DisplayLengthValue, Rel, Requirement, Side};
+///
+/// let display_length = DisplayLengthBuilder::new(DisplayLengthValue::Value(768))
+///     .compare(Rel::Le)
+///     .side(Side::Longest)
+///     .build();
+/// let requirement = Requirement::DisplayLength(display_length);
+/// ```
+pub struct DisplayLengthBuilder {
+    /// The comparison operator.
+    pub compare: Rel,
+    /// The display length value.
+    pub value: DisplayLengthValue,
+    /// The measured side of the display.
+    pub side: Side,
+}
+
+#[allow(dead_code)]
+impl DisplayLengthBuilder {
+    /// Creates a new `DisplayLengthBuilder`
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The display length value.
+    pub fn new(value: DisplayLengthValue) -> Self {
+        Self {
+            compare: Rel::default(),
+            value,
+            side: Side::default(),
+        }
+    }
+
+    /// Sets the comparison operator.
+    #[must_use]
+    pub fn compare(mut self, compare: Rel) -> Self {
+        self.compare = compare;
+        self
+    }
+
+    /// Sets which side of the display is measured.
+    #[must_use]
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Constructs a `DisplayLength`.
+    #[must_use]
+    pub fn build(self) -> DisplayLength {
+        DisplayLength {
+            compare: self.compare,
+            value: self.value,
+            side: self.side,
         }
     }
 }
@@ -529,6 +673,8 @@ pub struct ImageBuilder {
     pub width: Option<u32>,
     /// The image height.
     pub height: Option<u32>,
+    /// The image scale factor.
+    pub scale: Option<u32>,
     /// The URL of the image.
     pub url: Url,
     /// The type of the image.
@@ -546,6 +692,7 @@ impl ImageBuilder {
         Self {
             width: None,
             height: None,
+            scale: None,
             url,
             kind: ImageKind::Source,
         }
@@ -572,12 +719,20 @@ impl ImageBuilder {
         self
     }
 
+    /// Sets the image scale factor.
+    #[must_use]
+    pub fn scale(mut self, scale: u32) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
     /// Constructs an `Image`.
     #[must_use]
     pub fn build(self) -> Image {
         Image {
             width: self.width,
             height: self.height,
+            scale: self.scale,
             url: self.url,
             kind: self.kind,
         }
@@ -628,9 +783,9 @@ impl LanguageBuilder {
 /// A helper to build a `Release`.
 pub struct ReleaseBuilder {
     /// The release date.
-    pub date: Option<DateTime<Utc>>,
+    pub date: Option<DateTime>,
     /// The end-of-life date of the release.
-    pub date_eol: Option<DateTime<Utc>>,
+    pub date_eol: Option<DateTime>,
     /// The release description.
     pub description: Option<MarkupTranslatableString>,
     /// The version of the release.
@@ -693,14 +848,14 @@ impl ReleaseBuilder {
 
     /// Sets the release date.
     #[must_use]
-    pub fn date(mut self, date: DateTime<Utc>) -> Self {
+    pub fn date(mut self, date: DateTime) -> Self {
         self.date = Some(date);
         self
     }
 
     /// Sets the End-of-life release date.
     #[must_use]
-    pub fn date_eol(mut self, date_eol: DateTime<Utc>) -> Self {
+    pub fn date_eol(mut self, date_eol: DateTime) -> Self {
         self.date_eol = Some(date_eol);
         self
     }
@@ -874,6 +1029,8 @@ pub struct VideoBuilder {
     pub codec: Option<String>,
     /// The video container. Possible values are Matroska(.mkv) or WebM.
     pub container: Option<String>,
+    /// A translatable short description of the video.
+    pub caption: Option<TranslatableString>,
     /// The video URL.
     pub url: Url,
 }
@@ -891,6 +1048,7 @@ impl VideoBuilder {
             height: None,
             container: None,
             codec: None,
+            caption: None,
             url,
         }
     }
@@ -923,6 +1081,15 @@ impl VideoBuilder {
         self
     }
 
+    /// Sets a short translatable description of the `Video`.
+    #[must_use]
+    pub fn caption(mut self, caption: TranslatableString) -> Self {
+        if !caption.is_empty() {
+            self.caption = Some(caption);
+        }
+        self
+    }
+
     /// Construct a Video.
     #[must_use]
     pub fn build(self) -> Video {
@@ -931,6 +1098,7 @@ impl VideoBuilder {
             height: self.height,
             codec: self.codec,
             container: self.container,
+            caption: self.caption,
             url: self.url,
         }
     }
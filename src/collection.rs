@@ -1,16 +1,22 @@
 use std::{
+    collections::{HashMap, HashSet},
     convert::TryFrom,
     fs::File,
-    io::BufReader,
+    hash::Hash,
+    io::{BufRead, BufReader},
     path::{Path, PathBuf},
 };
 
 #[cfg(feature = "gzip")]
 use flate2::read::GzDecoder;
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncRead, AsyncReadExt};
 use xmltree::Element;
 
 use super::{
+    builders::CollectionBuilder,
+    enums::{ComponentKind, MergeKind, Provide},
     error::{collection_from_result, CollectionParseError, ContextParseError, ParseError},
     AppId, Component,
 };
@@ -36,7 +42,36 @@ pub struct Collection {
     pub architecture: Option<String>,
 }
 
+/// The specification version used when none is provided.
+const DEFAULT_SPEC_VERSION: &str = "1.0";
+
 impl Collection {
+    /// Create a new `Collection` wrapping a single `Component`.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The specification version used on the collection.
+    /// * `component` - The component to wrap.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::{builders::ComponentBuilder, Collection, TranslatableString};
+    ///
+    /// let component = ComponentBuilder::default()
+    ///     .id("org.gnome.design.Contrast".into())
+    ///     .name(TranslatableString::with_default("Contrast"))
+    ///     .build();
+    ///
+    /// let collection = Collection::single("0.10", component.clone());
+    /// assert_eq!(collection.components, vec![component.clone()]);
+    ///
+    /// let collection = Collection::from(component);
+    /// assert_eq!(collection.version, "1.0");
+    /// ```
+    pub fn single(version: &str, component: Component) -> Self {
+        CollectionBuilder::new(version).component(component).build()
+    }
+
     /// Create a new `Collection` from an XML file.
     ///
     /// # Arguments
@@ -46,6 +81,26 @@ impl Collection {
         Ok(Self::from_path_(path)?)
     }
 
+    /// Create a new `Collection` from multiple XML files, merged with
+    /// [`Collection::merge`] in the given order.
+    ///
+    /// An empty collection is returned if no path is given.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - The paths to the collections.
+    pub fn from_paths(paths: &[PathBuf]) -> Result<Self, ParseError> {
+        let mut paths = paths.iter();
+        let mut collection = match paths.next() {
+            Some(path) => Self::from_path(path.clone())?,
+            None => return Ok(CollectionBuilder::new(DEFAULT_SPEC_VERSION).build()),
+        };
+        for path in paths {
+            collection.merge(Self::from_path(path.clone())?);
+        }
+        Ok(collection)
+    }
+
     /// Create a new `Collection` from an XML file; skipping invalid components.
     ///
     /// For each invalid component, an error is collected into the returned
@@ -96,6 +151,163 @@ impl Collection {
         Ok(collection)
     }
 
+    /// Create a new `Collection` from a possibly compressed XML file.
+    ///
+    /// The compression format is detected from the first bytes of the file.
+    /// Gzip and zstd compressed files require the `gzip` and `zstd` features
+    /// respectively, anything else is parsed as plain XML.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the collection.
+    pub fn from_compressed_path(path: PathBuf) -> Result<Self, ParseError> {
+        const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+        const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+        let mut file = BufReader::new(File::open(path)?);
+        let header = file.fill_buf()?;
+
+        let element = if header.starts_with(GZIP_MAGIC) {
+            #[cfg(feature = "gzip")]
+            {
+                Element::parse(GzDecoder::new(file))?
+            }
+            #[cfg(not(feature = "gzip"))]
+            return Err(ParseError::other(
+                "collection",
+                "gzip compressed collections require the gzip feature",
+            ));
+        } else if header.starts_with(ZSTD_MAGIC) {
+            #[cfg(feature = "zstd")]
+            {
+                Element::parse(zstd::Decoder::with_buffer(file)?)?
+            }
+            #[cfg(not(feature = "zstd"))]
+            return Err(ParseError::other(
+                "collection",
+                "zstd compressed collections require the zstd feature",
+            ));
+        } else {
+            Element::parse(file)?
+        };
+
+        Ok(Collection::try_from(&element)?)
+    }
+
+    #[cfg(feature = "json")]
+    /// Create a new `Collection` from a JSON file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the JSON collection.
+    pub fn from_json_path(path: PathBuf) -> Result<Self, ParseError> {
+        let file = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    #[cfg(feature = "json")]
+    /// Write the `Collection` to a JSON file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to write the JSON collection to.
+    pub fn to_json_path(&self, path: PathBuf) -> Result<(), ParseError> {
+        let file = std::io::BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    /// Create a new `Collection` from an asynchronous reader.
+    ///
+    /// The whole stream is read into memory first, the XML parsing then
+    /// happens on a blocking task to avoid stalling the runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The reader to read the XML collection from.
+    pub async fn from_async_reader<R: AsyncRead + Unpin>(
+        mut reader: R,
+    ) -> Result<Self, ParseError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        tokio::task::spawn_blocking(move || {
+            let element = Element::parse(bytes.as_slice())?;
+            let collection: Collection = Collection::try_from(&element)?;
+            Ok(collection)
+        })
+        .await
+        .map_err(std::io::Error::other)?
+    }
+
+    /// The specification version of the collection as a `(major, minor)`
+    /// pair. Missing or invalid parts are considered as `0`.
+    ///
+    /// The version changes what can be expected from the collection, for
+    /// example `0.8` collections still list the legacy `<mimetypes>` tag
+    /// that newer ones replaced with `<provides><mediatype>`.
+    pub fn spec_version(&self) -> (u32, u32) {
+        let mut parts = self
+            .version
+            .split('.')
+            .map(|part| part.trim().parse::<u32>().unwrap_or(0));
+        let major = parts.next().unwrap_or(0);
+        let minor = parts.next().unwrap_or(0);
+        (major, minor)
+    }
+
+    /// Whether the collection uses at least a specific version of the
+    /// specification.
+    ///
+    /// # Arguments
+    ///
+    /// * `major` - The major version.
+    /// * `minor` - The minor version.
+    pub fn is_at_least(&self, major: u32, minor: u32) -> bool {
+        self.spec_version() >= (major, minor)
+    }
+
+    /// Sets the origin of the collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The new origin, like `flathub`.
+    pub fn set_origin(&mut self, origin: impl Into<String>) {
+        self.origin = Some(origin.into());
+    }
+
+    /// Sets the targeted CPU architecture of the collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `architecture` - The new architecture, like `x86_64`.
+    pub fn set_architecture(&mut self, architecture: impl Into<String>) {
+        self.architecture = Some(architecture.into());
+    }
+
+    /// Returns the collection with a different origin.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The new origin, like `flathub`.
+    #[must_use]
+    pub fn with_origin(mut self, origin: impl Into<String>) -> Self {
+        self.set_origin(origin);
+        self
+    }
+
+    /// Returns the collection with a different targeted CPU architecture.
+    ///
+    /// # Arguments
+    ///
+    /// * `architecture` - The new architecture, like `x86_64`.
+    #[must_use]
+    pub fn with_architecture(mut self, architecture: impl Into<String>) -> Self {
+        self.set_architecture(architecture);
+        self
+    }
+
     /// Find the components that corresponds to a specific `AppId`
     pub fn find_by_id(&self, id: AppId) -> Vec<&Component> {
         // For some obscure reasons & history
@@ -108,6 +320,184 @@ impl Collection {
             .filter(|c| c.id == id || c.id == alternative_id)
             .collect::<Vec<&Component>>()
     }
+
+    /// Merge another collection into the current one.
+    ///
+    /// Components sharing the same id are merged with [`Component::merge`],
+    /// the others are appended. A component with a `MergeKind::Replace`
+    /// merge kind replaces the component sharing its id, while one with a
+    /// `MergeKind::RemoveComponent` merge kind removes it instead.
+    ///
+    /// The most recent specification version of both is kept, while the
+    /// origin and architecture are only kept when they don't differ, as the
+    /// merged collection no longer has a single one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The collection to merge.
+    pub fn merge(&mut self, other: Collection) {
+        if other.spec_version() > self.spec_version() {
+            self.version = other.version;
+        }
+        if self.origin != other.origin {
+            self.origin = None;
+        }
+        if self.architecture != other.architecture {
+            self.architecture = None;
+        }
+
+        for component in other.components {
+            if component.merge == MergeKind::RemoveComponent {
+                self.components.retain(|c| c.id != component.id);
+                continue;
+            }
+            match self.components.iter_mut().find(|c| c.id == component.id) {
+                Some(existing) if component.merge == MergeKind::Replace => *existing = component,
+                Some(existing) => existing.merge(&component),
+                None => self.components.push(component),
+            }
+        }
+    }
+
+    /// Keep only the components of the given kinds, in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `kinds` - The kinds of the components to keep.
+    pub fn retain_kind(&mut self, kinds: &[ComponentKind]) {
+        self.components.retain(|c| kinds.contains(&c.kind));
+    }
+
+    /// The components suitable for a maximum age, according to
+    /// [`ContentRating::minimum_age`](crate::ContentRating::minimum_age).
+    /// The components without a content rating are considered suitable for
+    /// any age.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_age` - The age of the user, e.g. `12`.
+    pub fn components_under_age(&self, max_age: u32) -> Vec<&Component> {
+        self.components
+            .iter()
+            .filter(|c| c.content_rating.as_ref().map_or(0, |r| r.minimum_age()) <= max_age)
+            .collect()
+    }
+
+    /// The unique ids of the collection components.
+    pub fn ids(&self) -> HashSet<&AppId> {
+        self.components.iter().map(|c| &c.id).collect()
+    }
+
+    /// Build an index of the components by the MIME types they support,
+    /// either through the legacy `<mimetypes>` tag or a `<mediatype>` in
+    /// their `<provides>`.
+    ///
+    /// The index borrows the collection, build it once and reuse it for
+    /// repeated lookups instead of scanning the components each time.
+    pub fn build_mimetype_index(&self) -> HashMap<String, Vec<&Component>> {
+        let mut index: HashMap<String, Vec<&Component>> = HashMap::new();
+        for component in &self.components {
+            for mimetype in component.all_mimetypes() {
+                insert_unique(&mut index, mimetype.to_string(), component);
+            }
+        }
+        index
+    }
+
+    /// Build an index of the components by the value of the items they
+    /// provide, e.g. a binary name, a library or a D-Bus name.
+    ///
+    /// The index borrows the collection, build it once and reuse it for
+    /// repeated lookups instead of scanning the components each time.
+    pub fn build_provides_index(&self) -> HashMap<String, Vec<&Component>> {
+        let mut index: HashMap<String, Vec<&Component>> = HashMap::new();
+        for component in &self.components {
+            for provide in &component.provides {
+                let value = match provide {
+                    Provide::Firmware { item, .. } => item.clone(),
+                    Provide::Id(id) => id.0.clone(),
+                    Provide::Unknown { value, .. } => value.clone(),
+                    Provide::MediaType(value)
+                    | Provide::Library(value)
+                    | Provide::Binary(value)
+                    | Provide::Font(value)
+                    | Provide::Modalias(value)
+                    | Provide::Python2(value)
+                    | Provide::Python3(value)
+                    | Provide::DBus(value)
+                    | Provide::Codec(value) => value.clone(),
+                };
+                insert_unique(&mut index, value, component);
+            }
+        }
+        index
+    }
+
+    /// Build an index of the components by the ids they extend, the inverse
+    /// of [`Component::extends`], e.g. to list the addons of an application.
+    ///
+    /// The index borrows the collection, build it once and reuse it for
+    /// repeated lookups instead of scanning the components each time.
+    pub fn extends_index(&self) -> HashMap<&AppId, Vec<&Component>> {
+        let mut index: HashMap<&AppId, Vec<&Component>> = HashMap::new();
+        for component in &self.components {
+            for id in &component.extends {
+                insert_unique(&mut index, id, component);
+            }
+        }
+        index
+    }
+}
+
+fn insert_unique<'a, K: Eq + Hash>(
+    index: &mut HashMap<K, Vec<&'a Component>>,
+    key: K,
+    component: &'a Component,
+) {
+    let components = index.entry(key).or_default();
+    if !components.iter().any(|c| std::ptr::eq(*c, component)) {
+        components.push(component);
+    }
+}
+
+impl From<Component> for Collection {
+    fn from(component: Component) -> Self {
+        Self::single(DEFAULT_SPEC_VERSION, component)
+    }
+}
+
+/// Collect components into a `Collection` using the default specification
+/// version, without an origin or an architecture.
+///
+/// # Example
+/// ```
+/// use appstream::{builders::ComponentBuilder, Collection, TranslatableString};
+///
+/// let collection = ["org.gnome.design.Contrast", "org.gnome.design.Palette"]
+///     .into_iter()
+///     .map(|id| {
+///         ComponentBuilder::default()
+///             .id(id.into())
+///             .name(TranslatableString::with_default(id))
+///             .build()
+///     })
+///     .collect::<Collection>();
+/// assert_eq!(collection.version, "1.0");
+/// assert_eq!(collection.components.len(), 2);
+/// assert!(collection.origin.is_none());
+/// ```
+impl FromIterator<Component> for Collection {
+    fn from_iter<I: IntoIterator<Item = Component>>(iter: I) -> Self {
+        let mut collection = CollectionBuilder::new(DEFAULT_SPEC_VERSION).build();
+        collection.extend(iter);
+        collection
+    }
+}
+
+impl Extend<Component> for Collection {
+    fn extend<I: IntoIterator<Item = Component>>(&mut self, iter: I) {
+        self.components.extend(iter);
+    }
 }
 
 #[cfg(test)]
@@ -153,6 +543,18 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn async_reader_collection() -> Result<(), Box<dyn Error>> {
+        let bytes = std::fs::read("./tests/collections/fedora-other-repos.xml")?;
+        let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+
+        let c1 = runtime.block_on(Collection::from_async_reader(bytes.as_slice()))?;
+        let c2 = Collection::from_path("./tests/collections/fedora-other-repos.xml".into())?;
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
     #[test]
     fn spec_example_collection() -> Result<(), Box<dyn Error>> {
         let c1 = Collection::from_path("./tests/collections/spec_example.xml".into())?;
@@ -243,6 +645,304 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn compressed_collection() -> Result<(), Box<dyn Error>> {
+        let c1 = Collection::from_path("./tests/collections/spec_example.xml".into())?;
+
+        let c2 = Collection::from_compressed_path("./tests/collections/spec_example.xml".into())?;
+        assert_eq!(c1, c2);
+
+        let gzipped =
+            Collection::from_compressed_path("./tests/collections/spec_example.xml.gz".into());
+        #[cfg(feature = "gzip")]
+        assert_eq!(c1, gzipped?);
+        #[cfg(not(feature = "gzip"))]
+        assert!(gzipped.is_err());
+
+        let zstd_compressed =
+            Collection::from_compressed_path("./tests/collections/spec_example.xml.zst".into());
+        #[cfg(feature = "zstd")]
+        assert_eq!(c1, zstd_compressed?);
+        #[cfg(not(feature = "zstd"))]
+        assert!(zstd_compressed.is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn json_path_collection() -> Result<(), Box<dyn Error>> {
+        let c1 = Collection::from_path("./tests/collections/spec_example.xml".into())?;
+
+        let path = std::env::temp_dir().join("appstream-json-path-collection.json");
+        c1.to_json_path(path.clone())?;
+        let c2 = Collection::from_json_path(path.clone())?;
+        std::fs::remove_file(path)?;
+
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
+    #[test]
+    fn collection_origin_architecture() -> Result<(), Box<dyn Error>> {
+        let mut c = Collection::from_path("./tests/collections/spec_example.xml".into())?;
+        assert_eq!(c.origin, None);
+
+        c.set_origin("flathub");
+        c.set_architecture(String::from("x86_64"));
+        assert_eq!(c.origin.as_deref(), Some("flathub"));
+        assert_eq!(c.architecture.as_deref(), Some("x86_64"));
+
+        let c = c.with_origin("flathub-beta").with_architecture("aarch64");
+        assert_eq!(c.origin.as_deref(), Some("flathub-beta"));
+        assert_eq!(c.architecture.as_deref(), Some("aarch64"));
+        Ok(())
+    }
+
+    #[test]
+    fn collection_spec_version() {
+        let c1 = CollectionBuilder::new("0.8").build();
+        assert_eq!(c1.spec_version(), (0, 8));
+        assert!(c1.is_at_least(0, 8));
+        assert!(!c1.is_at_least(0, 10));
+
+        let c2 = CollectionBuilder::new("0.10").build();
+        assert_eq!(c2.spec_version(), (0, 10));
+        assert!(c2.is_at_least(0, 8));
+        assert!(c2.is_at_least(0, 10));
+        assert!(!c2.is_at_least(1, 0));
+    }
+
+    #[test]
+    fn collection_ids() -> Result<(), Box<dyn Error>> {
+        let c = Collection::from_path("./tests/collections/spec_example.xml".into())?;
+
+        let ids = c.ids();
+        assert_eq!(ids.len(), c.components.len());
+        assert!(ids.contains(&AppId::from("org.mozilla.Firefox")));
+        assert!(!ids.contains(&AppId::from("org.gnome.design.Contrast")));
+        Ok(())
+    }
+
+    #[test]
+    fn collection_group_by_kind() -> Result<(), Box<dyn Error>> {
+        let c = Collection::from_path("./tests/collections/spec_example.xml".into())?;
+
+        let mut kinds: HashMap<ComponentKind, Vec<&Component>> = HashMap::new();
+        for component in &c.components {
+            kinds
+                .entry(component.kind.clone())
+                .or_default()
+                .push(component);
+        }
+        assert_eq!(
+            kinds[&ComponentKind::DesktopApplication][0].id,
+            "org.mozilla.Firefox".into()
+        );
+        assert_eq!(
+            kinds.values().map(Vec::len).sum::<usize>(),
+            c.components.len()
+        );
+
+        let categories: HashSet<&Category> =
+            c.components.iter().flat_map(|c| &c.categories).collect();
+        assert!(categories.contains(&Category::Unknown("network".into())));
+
+        let provides: HashSet<&Provide> = c.components.iter().flat_map(|c| &c.provides).collect();
+        assert!(provides.contains(&Provide::Binary("firefox".into())));
+        Ok(())
+    }
+
+    #[test]
+    fn collection_retain_kind() -> Result<(), Box<dyn Error>> {
+        let mut c = Collection::from_path("./tests/collections/spec_example.xml".into())?;
+        assert!(c
+            .components
+            .iter()
+            .any(|c| c.kind != ComponentKind::DesktopApplication));
+
+        c.retain_kind(&[ComponentKind::DesktopApplication]);
+        assert_eq!(c.components.len(), 1);
+        assert_eq!(c.components[0].id, "org.mozilla.Firefox".into());
+
+        c.retain_kind(&[]);
+        assert!(c.components.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn collection_components_under_age() {
+        use crate::{
+            enums::{ContentAttribute, ContentRatingVersion, ContentState},
+            ContentRating,
+        };
+
+        let component = |id: &str, attributes: Option<Vec<ContentAttribute>>| {
+            let builder = ComponentBuilder::default()
+                .id(id.into())
+                .name(TranslatableString::with_default(id));
+            match attributes {
+                Some(attributes) => builder.content_rating(ContentRating {
+                    version: ContentRatingVersion::Oars1_1,
+                    attributes,
+                }),
+                None => builder,
+            }
+            .build()
+        };
+        let c = CollectionBuilder::new("1.0")
+            .component(component("com.example.unrated", None))
+            .component(component("com.example.everyone", Some(vec![])))
+            .component(component(
+                "com.example.cartoon",
+                Some(vec![ContentAttribute::ViolenceCartoon(ContentState::Mild)]),
+            ))
+            .component(component(
+                "com.example.gambling",
+                Some(vec![
+                    ContentAttribute::ViolenceCartoon(ContentState::Mild),
+                    ContentAttribute::MoneyGambling(ContentState::Intense),
+                ]),
+            ))
+            .build();
+
+        let ids = |max_age| {
+            c.components_under_age(max_age)
+                .iter()
+                .map(|c| c.id.0.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(0), vec!["com.example.unrated", "com.example.everyone"]);
+        assert_eq!(
+            ids(3),
+            vec![
+                "com.example.unrated",
+                "com.example.everyone",
+                "com.example.cartoon"
+            ]
+        );
+        assert_eq!(ids(18).len(), 4);
+    }
+
+    #[test]
+    fn collection_indexes() -> Result<(), Box<dyn Error>> {
+        let c = Collection::from_path("./tests/collections/spec_example.xml".into())?;
+
+        let mimetypes = c.build_mimetype_index();
+        let components = mimetypes.get("text/html").unwrap();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].id, "org.mozilla.Firefox".into());
+        assert!(!mimetypes.contains_key("image/png"));
+
+        let provides = c.build_provides_index();
+        let components = provides.get("libpulse.so.0").unwrap();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].id, "org.freedesktop.PulseAudio".into());
+        assert_eq!(
+            provides.get("firefox").unwrap()[0].id,
+            "org.mozilla.Firefox".into()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn collection_from_paths() -> Result<(), Box<dyn Error>> {
+        let spec = Collection::from_path("./tests/collections/spec_example.xml".into())?;
+        let fedora = Collection::from_path("./tests/collections/fedora-other-repos.xml".into())?;
+
+        let c = Collection::from_paths(&[
+            "./tests/collections/fedora-other-repos.xml".into(),
+            "./tests/collections/spec_example.xml".into(),
+        ])?;
+        assert_eq!(c.version, "0.10");
+        assert_eq!(c.origin, None);
+        assert_eq!(
+            c.components.len(),
+            spec.components.len() + fedora.components.len()
+        );
+        assert_eq!(c.components[0], fedora.components[0]);
+        assert_eq!(c.components[fedora.components.len()], spec.components[0]);
+
+        let c = Collection::from_paths(&[
+            "./tests/collections/fedora-other-repos.xml".into(),
+            "./tests/collections/fedora-other-repos.xml".into(),
+        ])?;
+        assert_eq!(c, fedora);
+
+        let c = Collection::from_paths(&[])?;
+        assert!(c.components.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn collection_merge_kind() -> Result<(), Box<dyn Error>> {
+        let xml = r"
+            <components version='0.14'>
+                <component merge='append'>
+                    <id>org.mozilla.Firefox</id>
+                    <name>Mozilla Firefox</name>
+                    <releases>
+                        <release version='999.0' />
+                    </releases>
+                </component>
+                <component merge='remove-component'>
+                    <id>org.freedesktop.PulseAudio</id>
+                    <name>PulseAudio</name>
+                </component>
+                <component merge='replace'>
+                    <id>org.linuxlibertine.LinuxLibertine</id>
+                    <name>Libertine</name>
+                </component>
+            </components>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let merges = Collection::try_from(&element).map_err(ParseError::from)?;
+        assert_eq!(merges.components[0].merge, MergeKind::Append);
+        assert_eq!(merges.components[1].merge, MergeKind::RemoveComponent);
+        assert_eq!(merges.components[2].merge, MergeKind::Replace);
+        let replacement = merges.components[2].clone();
+
+        let mut c = Collection::from_path("./tests/collections/spec_example.xml".into())?;
+        let len = c.components.len();
+        let firefox = c.find_by_id("org.mozilla.Firefox".into())[0].clone();
+        c.merge(merges);
+        assert_eq!(c.components.len(), len - 1);
+        assert!(c.find_by_id("org.freedesktop.PulseAudio".into()).is_empty());
+
+        // The appended data is merged into the existing component.
+        let merged = c.find_by_id("org.mozilla.Firefox".into());
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].name, firefox.name);
+        assert_eq!(merged[0].screenshots, firefox.screenshots);
+        assert!(merged[0].release("999.0").is_some());
+
+        // The replaced component is swapped for the new one.
+        assert_eq!(
+            c.find_by_id("org.linuxlibertine.LinuxLibertine".into()),
+            vec![&replacement]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn collection_extends_index() -> Result<(), Box<dyn Error>> {
+        let addon = Component::from_path("./tests/addon.xml".into())?;
+        let localization = Component::from_path("./tests/localization.xml".into())?;
+        let c = CollectionBuilder::new("0.14")
+            .component(addon)
+            .component(localization)
+            .build();
+
+        let index = c.extends_index();
+        let addons = index.get(&AppId::from("org.gnome.gedit")).unwrap();
+        assert_eq!(addons.len(), 1);
+        assert_eq!(addons[0].id, "org.gnome.gedit_code_assistance".into());
+        assert_eq!(
+            index.get(&AppId::from("org.kde.dolphin.desktop")).unwrap()[0].id,
+            "org.kde.l10n.de".into()
+        );
+        assert!(!index.contains_key(&AppId::from("org.gnome.gedit_code_assistance")));
+        Ok(())
+    }
+
     #[test]
     fn generic_collection() -> Result<(), Box<dyn Error>> {
         let c1 = Collection::from_path("./tests/collections/fedora-other-repos.xml".into())?;
@@ -1,17 +1,31 @@
-use std::{collections::HashMap, convert::TryFrom, fs::File, io::BufReader, path::PathBuf};
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    convert::TryFrom,
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 #[cfg(feature = "gzip")]
 use flate2::read::GzDecoder;
 use serde::{Deserialize, Serialize};
+use url::Url;
 use xmltree::Element;
 
 use super::{
     enums::{
-        Bundle, Category, ComponentKind, Icon, Kudo, Launchable, ProjectUrl, Provide, Translation,
+        Bundle, Category, ComponentKind, ContentRatingVersion, Icon, ImageKind, Kudo, Launchable,
+        MergeKind, ProjectUrl, ProjectUrlKind, Provide, ReleaseOrder, Size, Translation,
     },
     error::ParseError,
-    AppId, ContentRating, Language, License, MarkupTranslatableString, Release, Requirement,
-    Screenshot, TranslatableList, TranslatableString,
+    normalize_locale,
+    translatable_string::DEFAULT_LOCALE,
+    xml::parse_component,
+    AppId, Branding, ContentRating, Control, DisplayLength, Language, License,
+    MarkupTranslatableString, Release, Requirement, Screenshot, TranslatableList,
+    TranslatableString, ValidationWarning,
 };
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 /// A component is wrapper around a `metainfo.xml` file or previously an
@@ -46,7 +60,9 @@ pub struct Component {
     pub summary: Option<TranslatableString>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    /// A long description of this component.
+    /// A long description of this component. If multiple `<description>`
+    /// tags share the same locale, the last one wins, see
+    /// `Component::validate_element` to report them.
     pub description: Option<MarkupTranslatableString>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -117,6 +133,15 @@ pub struct Component {
     /// Metainformation that describes the various releases.
     pub releases: Vec<Release>,
 
+    #[serde(default, skip_serializing_if = "is_default_release_order")]
+    /// The order in which the releases are listed.
+    pub release_order: ReleaseOrder,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The URL of the external releases file, set by a
+    /// `<releases type="external">` tag.
+    pub releases_url: Option<Url>,
+
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     /// The languages supported by the component.
     pub languages: Vec<Language>,
@@ -133,12 +158,22 @@ pub struct Component {
     /// A list of keywords, to help the user find the component easily.
     pub keywords: Option<TranslatableList>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The keywords marked as machine translated with a `translated="yes"`
+    /// attribute, a subset of `keywords`.
+    pub machine_translated_keywords: Option<TranslatableList>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     /// Specifies the age rating of the component.
     pub content_rating: Option<ContentRating>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The branding of the component.
+    pub branding: Option<Branding>,
+
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    /// Public interfaces the component provides.
+    /// Public interfaces the component provides, in the order they are
+    /// listed. The order is significant, e.g. for the codecs capabilities.
     pub provides: Vec<Provide>,
 
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -152,6 +187,64 @@ pub struct Component {
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     /// Custom metadata.
     pub metadata: HashMap<String, Option<String>>,
+
+    #[serde(default, skip_serializing_if = "is_default_merge_kind")]
+    /// How the component merges with the one sharing its id, see
+    /// `Collection::merge`.
+    pub merge: MergeKind,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// The data `Component::strip` drops, nothing by default.
+///
+/// # Example
+/// ```
+/// use appstream::StripOptions;
+///
+/// let options = StripOptions::default().releases().screenshots();
+/// assert!(options.releases && options.screenshots && !options.description);
+/// assert_eq!(StripOptions::all(), options.description());
+/// ```
+pub struct StripOptions {
+    /// Whether to drop the releases.
+    pub releases: bool,
+    /// Whether to drop the screenshots.
+    pub screenshots: bool,
+    /// Whether to drop the description.
+    pub description: bool,
+}
+
+impl StripOptions {
+    /// Drop everything but the search-relevant data: the releases, the
+    /// screenshots and the description.
+    pub fn all() -> Self {
+        Self {
+            releases: true,
+            screenshots: true,
+            description: true,
+        }
+    }
+
+    /// Drop the releases.
+    #[must_use]
+    pub fn releases(mut self) -> Self {
+        self.releases = true;
+        self
+    }
+
+    /// Drop the screenshots.
+    #[must_use]
+    pub fn screenshots(mut self) -> Self {
+        self.screenshots = true;
+        self
+    }
+
+    /// Drop the description.
+    #[must_use]
+    pub fn description(mut self) -> Self {
+        self.description = true;
+        self
+    }
 }
 
 impl Component {
@@ -195,29 +288,740 @@ impl Component {
         let component: Component = Component::try_from(&element)?;
         Ok(component)
     }
+
+    #[cfg(feature = "json")]
+    /// Create a new `Component` from a JSON file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the JSON component.
+    pub fn from_json_path(path: PathBuf) -> Result<Self, ParseError> {
+        let file = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    #[cfg(feature = "json")]
+    /// Write the `Component` to a JSON file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to write the JSON component to.
+    pub fn to_json_path(&self, path: PathBuf) -> Result<(), ParseError> {
+        let file = std::io::BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+
+    /// Create a new `Component` from an XML element in strict mode.
+    ///
+    /// Unlike `Component::try_from`, which silently drops the data it
+    /// recognizes but cannot represent yet, like the `<firmware>` or
+    /// `<kernel>` requirements, this fails with `ParseError::Unsupported`.
+    /// The icons are parsed with `Icon::try_from_strict` as well and the
+    /// unrecognized tags in `<provides>` are rejected instead of being kept
+    /// as `Provide::Unknown`, like the provided ids that are not reverse-DNS
+    /// names.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - The `<component>` element.
+    pub fn try_from_strict(element: &Element) -> Result<Self, ParseError> {
+        parse_component(element, true, true).map(|(component, _)| component)
+    }
+
+    /// Create a new `Component` from an XML element, tolerating a missing
+    /// `<id>`.
+    ///
+    /// Partial metadata, e.g. a component being streamed, is still parsed
+    /// into a component with an empty id and a
+    /// `ValidationWarning::MissingId` is returned alongside. Any other error
+    /// is reported like `Component::try_from` does.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - The `<component>` element.
+    pub fn try_from_partial(
+        element: &Element,
+    ) -> Result<(Self, Vec<ValidationWarning>), ParseError> {
+        let (component, mut warnings) = parse_component(element, false, false)?;
+        if component.id.0.is_empty() {
+            warnings.push(ValidationWarning::MissingId);
+        }
+        Ok((component, warnings))
+    }
+
+    /// Validate a `<component>` element against the specifications.
+    ///
+    /// On top of the issues reported by `Component::validate`, the ones that
+    /// are lost once the component is parsed are returned as well, like a
+    /// `ValidationWarning::DuplicateDescription`. A missing `<id>` is reported
+    /// as a `ValidationWarning::MissingId`, see `Component::try_from_partial`.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - The `<component>` element.
+    pub fn validate_element(element: &Element) -> Result<Vec<ValidationWarning>, ParseError> {
+        let (component, mut warnings) = Self::try_from_partial(element)?;
+
+        let component_element = if element.name == "components" {
+            element.get_child("component")
+        } else {
+            Some(element)
+        };
+        if let Some(component_element) = component_element {
+            let mut locales = Vec::new();
+            for description in component_element
+                .children
+                .iter()
+                .filter_map(|node| node.as_element())
+                .filter(|e| e.name == "description")
+            {
+                let locale = description
+                    .attributes
+                    .get("lang")
+                    .map_or_else(|| DEFAULT_LOCALE.to_string(), |l| normalize_locale(l));
+                if locales.contains(&locale) {
+                    warnings.push(ValidationWarning::DuplicateDescription(locale));
+                } else {
+                    locales.push(locale);
+                }
+            }
+
+            let screenshots = component_element
+                .get_child("screenshots")
+                .into_iter()
+                .flat_map(|e| e.children.iter())
+                .chain(component_element.children.iter())
+                .filter_map(|node| node.as_element())
+                .filter(|e| e.name == "screenshot");
+            for screenshot in screenshots {
+                if let Some(kind) = screenshot.attributes.get("type") {
+                    if kind != "default" && kind != "extra" {
+                        warnings.push(ValidationWarning::UnknownScreenshotType(kind.clone()));
+                    }
+                }
+                for image in screenshot
+                    .children
+                    .iter()
+                    .filter_map(|node| node.as_element())
+                    .filter(|e| e.name == "image")
+                {
+                    if let Some(kind) = image.attributes.get("type") {
+                        if ImageKind::from_str(&kind.to_lowercase()).is_err() {
+                            warnings.push(ValidationWarning::UnknownImageType(kind.clone()));
+                        }
+                    }
+                }
+            }
+        }
+
+        warnings.extend(component.validate());
+        Ok(warnings)
+    }
+
+    /// The raw `type` of the component when it's not one known by the
+    /// specifications, e.g. `mimetype-handler`.
+    pub fn raw_kind(&self) -> Option<&str> {
+        match &self.kind {
+            ComponentKind::Unknown(kind) => Some(kind),
+            _ => None,
+        }
+    }
+
+    /// The OARS version of the component content rating, if any.
+    pub fn oars_version(&self) -> Option<ContentRatingVersion> {
+        self.content_rating.as_ref().map(|c| c.version)
+    }
+
+    /// The main categories the component is associated with, see
+    /// `Category::is_main`.
+    pub fn main_categories(&self) -> Vec<&Category> {
+        self.categories.iter().filter(|c| c.is_main()).collect()
+    }
+
+    /// Whether the component is assigned to a category.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category to look for.
+    pub fn has_category(&self, category: &Category) -> bool {
+        self.categories.contains(category)
+    }
+
+    /// Whether the component has any screenshots.
+    pub fn has_screenshots(&self) -> bool {
+        !self.screenshots.is_empty()
+    }
+
+    /// Returns the screenshot marked as the default one, or the first
+    /// screenshot if none is.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::Component;
+    ///
+    /// let component = Component::from_path("./tests/desktop.xml".into()).unwrap();
+    /// assert!(component.has_screenshots());
+    ///
+    /// let screenshot = component.default_screenshot().unwrap();
+    /// assert!(screenshot.is_default);
+    /// assert_eq!(
+    ///     screenshot.images[0].url.as_str(),
+    ///     "http://www.hughsie.com/en_US/main.png"
+    /// );
+    /// ```
+    pub fn default_screenshot(&self) -> Option<&Screenshot> {
+        self.screenshots
+            .iter()
+            .find(|s| s.is_default)
+            .or_else(|| self.screenshots.first())
+    }
+
+    /// Returns the display length the component requires, if any.
+    pub fn required_display_length(&self) -> Option<&DisplayLength> {
+        self.requires.iter().find_map(|r| match r {
+            Requirement::DisplayLength(display_length) => Some(display_length),
+            _ => None,
+        })
+    }
+
+    /// Returns the display lengths the component supports.
+    pub fn supported_display_lengths(&self) -> Vec<&DisplayLength> {
+        self.supports
+            .iter()
+            .filter_map(|r| match r {
+                Requirement::DisplayLength(display_length) => Some(display_length),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The controls the component requires.
+    pub fn required_controls(&self) -> Vec<Control> {
+        controls(&self.requires)
+    }
+
+    /// The controls the component recommends.
+    pub fn recommended_controls(&self) -> Vec<Control> {
+        controls(&self.recommends)
+    }
+
+    /// The controls the component supports.
+    pub fn supported_controls(&self) -> Vec<Control> {
+        controls(&self.supports)
+    }
+
+    /// The MIME types the component supports, either listed in the legacy
+    /// `<mimetypes>` tag or as a `<mediatype>` in `<provides>`.
+    pub fn all_mimetypes(&self) -> Vec<&str> {
+        let media_types = self.provides.iter().filter_map(|p| match p {
+            Provide::MediaType(media_type) => Some(media_type),
+            _ => None,
+        });
+
+        let mut mimetypes = Vec::new();
+        for mimetype in self.mimetypes.iter().chain(media_types) {
+            if !mimetypes.contains(&mimetype.as_str()) {
+                mimetypes.push(mimetype.as_str());
+            }
+        }
+        mimetypes
+    }
+
+    /// Whether the component provides a binary, matched either by its full
+    /// path or by its name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The binary name or path, e.g. `foobar`.
+    pub fn provides_binary(&self, name: &str) -> bool {
+        self.provides.iter().any(|p| match p {
+            Provide::Binary(binary) => binary == name || p.binary_name() == Some(name),
+            _ => false,
+        })
+    }
+
+    /// The desktop file id of the component.
+    ///
+    /// The first `Launchable::DesktopId` is used, desktop applications
+    /// without one fall back to their id with a `.desktop` suffix.
+    pub fn desktop_id(&self) -> Option<String> {
+        let launchable = self.launchables.iter().find_map(|l| match l {
+            Launchable::DesktopId(desktop_id) => Some(desktop_id.clone()),
+            _ => None,
+        });
+        launchable.or_else(|| match self.kind {
+            ComponentKind::DesktopApplication if self.id.0.ends_with(".desktop") => {
+                Some(self.id.0.clone())
+            }
+            ComponentKind::DesktopApplication => Some(format!("{}.desktop", self.id)),
+            _ => None,
+        })
+    }
+
+    /// The name of the first `Launchable::Service`, e.g. a systemd unit.
+    pub fn service_launchable(&self) -> Option<&str> {
+        self.launchables.iter().find_map(|l| match l {
+            Launchable::Service(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The name of the first `Launchable::CockpitManifest`.
+    pub fn cockpit_manifest(&self) -> Option<&str> {
+        self.launchables.iter().find_map(|l| match l {
+            Launchable::CockpitManifest(manifest) => Some(manifest.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Make the relative paths of the cached and local icons absolute,
+    /// against a base directory, e.g. the directory of the metainfo file.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_dir` - The directory the icon paths are relative to.
+    pub fn resolve_local_icons(&mut self, base_dir: &Path) {
+        for icon in &mut self.icons {
+            if let Icon::Cached { path, .. } | Icon::Local { path, .. } = icon {
+                if path.is_relative() {
+                    *path = base_dir.join(&*path);
+                }
+            }
+        }
+    }
+
+    /// The first URL of a specific kind, e.g. the homepage.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of the URL.
+    pub fn url(&self, kind: ProjectUrlKind) -> Option<&Url> {
+        self.urls.iter().find(|u| u.kind() == kind).map(|u| u.url())
+    }
+
+    /// All the URLs of a specific kind, in the order they are listed.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of the URLs.
+    pub fn urls_of_kind(&self, kind: ProjectUrlKind) -> Vec<&Url> {
+        self.urls
+            .iter()
+            .filter(|u| u.kind() == kind)
+            .map(|u| u.url())
+            .collect()
+    }
+
+    /// Find the release with a specific version.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The version of the release.
+    pub fn release(&self, version: &str) -> Option<&Release> {
+        self.releases.iter().find(|r| r.version == version)
+    }
+
+    /// The latest release of the component, according to the order the
+    /// releases are listed in.
+    pub fn latest_release(&self) -> Option<&Release> {
+        match self.release_order {
+            ReleaseOrder::Inverse => self.releases.first(),
+            ReleaseOrder::Ascending => self.releases.last(),
+        }
+    }
+
+    /// Load the releases from the external releases file referenced by
+    /// `releases_url`, replacing the current ones.
+    ///
+    /// The file is fetched with a user-provided resolver, keeping the crate
+    /// network-agnostic. Nothing is done if the component has no external
+    /// releases URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `resolver` - Returns the content of the releases file at a URL.
+    pub fn load_external_releases(
+        &mut self,
+        resolver: impl Fn(&Url) -> Result<Vec<u8>, std::io::Error>,
+    ) -> Result<(), ParseError> {
+        let url = match &self.releases_url {
+            Some(url) => url,
+            None => return Ok(()),
+        };
+        let bytes = resolver(url)?;
+        let element = Element::parse(bytes.as_slice())?;
+        if element.name != "releases" {
+            return Err(ParseError::invalid_tag(&element.name));
+        }
+
+        self.releases = element
+            .children
+            .iter()
+            .filter_map(|node| node.as_element())
+            .filter(|e| e.name == "release")
+            .map(Release::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+
+    /// The download size in bytes of the latest release, if known.
+    ///
+    /// Only the release returned by `Component::latest_release` is consulted,
+    /// the sizes of the older releases are not used as a fallback.
+    pub fn download_size(&self) -> Option<u64> {
+        self.latest_release()?.sizes.iter().find_map(|s| match s {
+            Size::Download(size) => Some(*size),
+            _ => None,
+        })
+    }
+
+    /// The installed size in bytes of the latest release, if known.
+    ///
+    /// Like `Component::download_size`, only the latest release is consulted.
+    pub fn installed_size(&self) -> Option<u64> {
+        self.latest_release()?.sizes.iter().find_map(|s| match s {
+            Size::Installed(size) => Some(*size),
+            _ => None,
+        })
+    }
+
+    /// Whether the component extends another one.
+    ///
+    /// The ids are compared regardless of a `.desktop` suffix.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the extended component.
+    ///
+    /// ```
+    /// use appstream::Component;
+    ///
+    /// let component = Component::from_path("./tests/localization.xml".into()).unwrap();
+    /// assert!(component.extends_id(&"org.kde.plasmashell".into()));
+    /// assert!(component.extends_id(&"org.kde.gwenview".into()));
+    /// assert!(component.extends_id(&"org.kde.dolphin.desktop".into()));
+    /// assert!(!component.extends_id(&"org.kde.konsole".into()));
+    /// ```
+    pub fn extends_id(&self, id: &AppId) -> bool {
+        self.extends.iter().any(|e| same_id(e, id))
+    }
+
+    /// Whether the component suggests installing another one.
+    ///
+    /// The ids are compared regardless of a `.desktop` suffix.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the suggested component.
+    ///
+    /// ```
+    /// use appstream::Component;
+    ///
+    /// let component = Component::from_path("./tests/localization.xml".into()).unwrap();
+    /// assert!(!component.suggests_id(&"org.kde.plasmashell".into()));
+    /// ```
+    pub fn suggests_id(&self, id: &AppId) -> bool {
+        self.suggestions.iter().any(|s| same_id(s, id))
+    }
+
+    /// Check the component against the specifications for issues that are
+    /// not fatal to the parsing. See `Component::validate_element` for the
+    /// issues only visible in the XML.
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+
+        // A typeless component defaults to a generic one, which is most
+        // likely an authoring mistake for a desktop application.
+        if self.kind == ComponentKind::Generic
+            && self
+                .launchables
+                .iter()
+                .any(|l| matches!(l, Launchable::DesktopId(_)))
+        {
+            warnings.push(ValidationWarning::MissingComponentType);
+        }
+
+        for video in self.screenshots.iter().flat_map(|s| &s.videos) {
+            if !video.is_spec_compliant() {
+                warnings.push(ValidationWarning::UnsupportedVideo(video.url.clone()));
+            }
+        }
+
+        let mut versions = HashMap::new();
+        for release in &self.releases {
+            let count = versions.entry(release.version.as_str()).or_insert(0);
+            *count += 1;
+            if *count == 2 {
+                warnings.push(ValidationWarning::DuplicateReleaseVersion(
+                    release.version.clone(),
+                ));
+            }
+        }
+
+        warnings
+    }
+
+    /// Drop the data that is not needed to index the component, e.g. for
+    /// memory-constrained search indexers. The identity fields, the name,
+    /// summary, keywords and categories are always kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The data to drop.
+    pub fn strip(&mut self, options: StripOptions) {
+        if options.releases {
+            self.releases = Vec::new();
+        }
+        if options.screenshots {
+            self.screenshots = Vec::new();
+        }
+        if options.description {
+            self.description = None;
+        }
+    }
+
+    /// A copy of the component without its releases.
+    pub fn clone_without_releases(&self) -> Self {
+        let mut component = self.clone();
+        component.strip(StripOptions::default().releases());
+        component
+    }
+
+    /// Enrich the current component with the data of another one describing
+    /// the same software, e.g. an installed metainfo file and its collection
+    /// counterpart.
+    ///
+    /// The identity fields of `self` are kept as is. The `screenshots`,
+    /// `icons` and `bundles` of `other` are only used if `self` has none,
+    /// while the `languages` and `releases` of both components are merged,
+    /// `self` taking precedence for entries with the same locale or version.
+    /// The missing releases are inserted according to the `release_order` of
+    /// `self`, see `Release::cmp_version`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The component to take the missing information from.
+    pub fn merge(&mut self, other: &Component) {
+        if self.screenshots.is_empty() {
+            self.screenshots = other.screenshots.clone();
+        }
+        if self.icons.is_empty() {
+            self.icons = other.icons.clone();
+        }
+        if self.bundles.is_empty() {
+            self.bundles = other.bundles.clone();
+        }
+
+        for language in &other.languages {
+            if !self.languages.iter().any(|l| l.locale == language.locale) {
+                self.languages.push(language.clone());
+            }
+        }
+        for release in &other.releases {
+            if self.releases.iter().any(|r| r.version == release.version) {
+                continue;
+            }
+            // The first release listed after the new one.
+            let after = match self.release_order {
+                ReleaseOrder::Inverse => Ordering::Less,
+                ReleaseOrder::Ascending => Ordering::Greater,
+            };
+            let index = self
+                .releases
+                .iter()
+                .position(|r| r.cmp_version(release) == after)
+                .unwrap_or(self.releases.len());
+            self.releases.insert(index, release.clone());
+        }
+    }
+
+    /// Export the component as a `.desktop` file `[Desktop Entry]` group,
+    /// with its `Name`, `Comment`, `Categories`, `MimeType` and `Keywords`.
+    ///
+    /// Only the default strings and the translations of the given locales
+    /// are written, e.g. `Name[de]=`. The values are escaped as described by
+    /// the Desktop Entry specification.
+    ///
+    /// The entry is always written with `Type=Application`, it is up to the
+    /// caller to only export the desktop, console or web applications.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale_list` - The locales to write the translations for.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::Component;
+    ///
+    /// let component = Component::from_path("./tests/desktop.xml".into()).unwrap();
+    /// let entry = component.to_desktop_entry(&[]);
+    /// assert!(entry.starts_with("[Desktop Entry]\n"));
+    /// assert!(entry.contains("\nName=Power Statistics\n"));
+    /// ```
+    pub fn to_desktop_entry(&self, locale_list: &[&str]) -> String {
+        let mut entry = String::from("[Desktop Entry]\nType=Application\n");
+
+        push_desktop_entry_key(&mut entry, "Name", &self.name, locale_list);
+        if let Some(summary) = &self.summary {
+            push_desktop_entry_key(&mut entry, "Comment", summary, locale_list);
+        }
+
+        if !self.categories.is_empty() {
+            let categories = self
+                .categories
+                .iter()
+                .map(|c| match c {
+                    Category::Unknown(category) => category.clone(),
+                    c => c.to_string(),
+                })
+                .collect::<Vec<_>>();
+            entry.push_str(&format!("Categories={}\n", desktop_entry_list(&categories)));
+        }
+
+        let mimetypes = self.all_mimetypes();
+        if !mimetypes.is_empty() {
+            entry.push_str(&format!("MimeType={}\n", desktop_entry_list(&mimetypes)));
+        }
+
+        if let Some(keywords) = &self.keywords {
+            if let Some(words) = keywords.0.get(DEFAULT_LOCALE) {
+                entry.push_str(&format!("Keywords={}\n", desktop_entry_list(words)));
+            }
+            for locale in locale_list {
+                let locale = normalize_locale(locale);
+                if let Some(words) = keywords.0.get(&locale) {
+                    entry.push_str(&format!(
+                        "Keywords[{}]={}\n",
+                        locale,
+                        desktop_entry_list(words)
+                    ));
+                }
+            }
+        }
+
+        entry
+    }
+}
+
+fn controls(requirements: &[Requirement]) -> Vec<Control> {
+    requirements
+        .iter()
+        .filter_map(|r| match r {
+            Requirement::Control(control) => Some(control.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn push_desktop_entry_key(
+    entry: &mut String,
+    key: &str,
+    text: &TranslatableString,
+    locale_list: &[&str],
+) {
+    if let Some(default) = text.get_default() {
+        entry.push_str(&format!(
+            "{}={}\n",
+            key,
+            escape_desktop_entry_value(default)
+        ));
+    }
+    for locale in locale_list {
+        if let Some(translation) = text.get_for_locale(locale) {
+            entry.push_str(&format!(
+                "{}[{}]={}\n",
+                key,
+                normalize_locale(locale),
+                escape_desktop_entry_value(translation)
+            ));
+        }
+    }
+}
+
+/// Escapes the characters that can't be written as is in a `.desktop` file
+/// value, like new lines.
+fn escape_desktop_entry_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+        .replace('\r', "\\r")
+}
+
+/// Joins the values of a `.desktop` file list, each one terminated by a `;`
+/// and the ones it contains being escaped.
+fn desktop_entry_list<S: AsRef<str>>(values: &[S]) -> String {
+    values
+        .iter()
+        .map(|value| {
+            format!(
+                "{};",
+                escape_desktop_entry_value(value.as_ref()).replace(';', "\\;")
+            )
+        })
+        .collect()
+}
+
+fn is_default_release_order(order: &ReleaseOrder) -> bool {
+    *order == ReleaseOrder::default()
+}
+
+fn is_default_merge_kind(merge: &MergeKind) -> bool {
+    *merge == MergeKind::default()
+}
+
+fn same_id(a: &AppId, b: &AppId) -> bool {
+    a.0.trim_end_matches(".desktop") == b.0.trim_end_matches(".desktop")
 }
 
 #[cfg(test)]
 mod tests {
 
-    use std::error::Error;
+    use std::{convert::TryFrom, error::Error};
 
+    #[cfg(feature = "chrono")]
     use chrono::{TimeZone, Utc};
     use url::Url;
 
     use super::Component;
+    #[cfg(feature = "chrono")]
+    use crate::{
+        builders::ArtifactBuilder,
+        enums::{ArtifactKind, Bundle, FirmwareKind, ImageKind, Launchable, Translation},
+        ContentRating,
+    };
     use crate::{
         builders::{
-            ArtifactBuilder, ComponentBuilder, ImageBuilder, LanguageBuilder, ReleaseBuilder,
-            ScreenshotBuilder,
+            ComponentBuilder, ImageBuilder, LanguageBuilder, ReleaseBuilder, ScreenshotBuilder,
+            VideoBuilder,
         },
         enums::{
-            ArtifactKind, Bundle, Category, ComponentKind, ContentRatingVersion, FirmwareKind,
-            Icon, ImageKind, Kudo, Launchable, ProjectUrl, Provide, ReleaseKind, Translation,
+            Category, ComponentKind, ContentAttribute, ContentRatingVersion, ContentState, Icon,
+            Kudo, ProjectUrl, Provide, ReleaseKind, ReleaseOrder,
         },
-        ContentRating, MarkupTranslatableString, TranslatableList, TranslatableString,
+        MarkupTranslatableString, ParseError, TranslatableList, TranslatableString,
+        ValidationWarning,
     };
 
+    /// Parses a `com.example.foobar` component named `Foo Bar`, with the given
+    /// children.
+    fn foobar_element(children: &str) -> Result<xmltree::Element, xmltree::ParseError> {
+        let xml = format!(
+            "<component><id>com.example.foobar</id><name>Foo Bar</name>{}</component>",
+            children
+        );
+        xmltree::Element::parse(xml.as_bytes())
+    }
+
+    /// A builder for the `com.example.foobar` component named `Foo Bar`, like
+    /// the one parsed by `foobar_element`.
+    fn foobar_builder() -> ComponentBuilder {
+        ComponentBuilder::default()
+            .id("com.example.foobar".into())
+            .name(TranslatableString::with_default("Foo Bar"))
+    }
+
     #[test]
     fn addon_component() -> Result<(), Box<dyn Error>> {
         let c1 = Component::from_path("./tests/addon.xml".into())?;
@@ -274,6 +1078,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn desktop_application_component() -> Result<(), Box<dyn Error>> {
         let c1: Component = Component::from_path("./tests/desktop.xml".into())?;
@@ -331,6 +1136,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn component_with_comment() -> Result<(), Box<dyn Error>> {
         let c1: Component = Component::from_path("./tests/component-with-comment.xml".into())?;
@@ -404,6 +1210,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn firmware_component() -> Result<(), Box<dyn Error>> {
         let c1 = Component::from_path("./tests/firmware.xml".into())?;
@@ -459,7 +1266,7 @@ mod tests {
             .metadata_license("MIT".into())
             .project_license("OFL-1.1".into())
             .summary(TranslatableString::with_default(
-                "A sanserif type­face fam­ily",
+                "A sanserif type\u{ad}face fam\u{ad}ily",
             ))
             .description(
                 MarkupTranslatableString::with_default(
@@ -477,13 +1284,12 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn generic_component() -> Result<(), Box<dyn Error>> {
         let c1 = Component::from_path("./tests/generic.xml".into())?;
 
-        let c2 = ComponentBuilder::default()
-            .id("com.example.foobar".into())
-            .name(TranslatableString::with_default("Foo Bar"))
+        let c2 = foobar_builder()
             .metadata_license("CC0-1.0".into())
             .summary(TranslatableString::with_default("A foo-ish bar"))
             .url(ProjectUrl::Homepage(Url::parse("http://www.example.org")?))
@@ -582,6 +1388,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn os_component() -> Result<(), Box<dyn Error>> {
         let c1: Component = Component::from_path("./tests/os.xml".into())?;
@@ -619,39 +1426,1313 @@ mod tests {
     }
 
     #[test]
-    fn runtime_component() -> Result<(), Box<dyn Error>> {
-        let c1: Component = Component::from_path("./tests/runtime.xml".into())?;
+    fn os_short_kind_component() -> Result<(), Box<dyn Error>> {
+        let xml = r"
+            <component type='os'>
+                <id>org.debian.debian</id>
+                <name>Debian GNU/Linux</name>
+            </component>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let c1 = Component::try_from(&element)?;
 
         let c2 = ComponentBuilder::default()
-            .id("org.freedesktop.Platform".into())
-            .name(TranslatableString::with_default("Freedesktop Platform"))
-            .kind(ComponentKind::Runtime)
-            .metadata_license("FSFAP".into())
-            .project_license("LicenseRef-free=https://freedesktop-sdk.gitlab.io/".into())
-            .summary(TranslatableString::with_default(
-                "Basic libraries to run Linux desktop applications",
-            ))
-            .description(
-                MarkupTranslatableString::with_default("<p>\n      The Freedesktop Platform is a runtime that contains the most basic libraries\n      and files needed to run a Linux desktop application.\n        </p>")
+            .id("org.debian.debian".into())
+            .name(TranslatableString::with_default("Debian GNU/Linux"))
+            .kind(ComponentKind::OS)
+            .build();
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
+    #[test]
+    fn keyless_metadata_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <custom>
+                <value key='x-appcenter-suggested-price'>5</value>
+                <value>orphan</value>
+            </custom>",
+        )?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = foobar_builder()
+            .metadata(
+                "x-appcenter-suggested-price".to_string(),
+                Some("5".to_string()),
             )
-            .url(ProjectUrl::Homepage(
-                Url::parse("https://freedesktop-sdk.gitlab.io/")?,
-            ))
-            .release(ReleaseBuilder::new("10.0").build())
-            .release(
-                ReleaseBuilder::new("9.0")
-                    .date(Utc.with_ymd_and_hms(2020, 01, 12, 0, 0, 0).unwrap())
+            .build();
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
+    #[test]
+    fn screenshots_with_stray_children_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <screenshots>
+                <screenshot type='default'>
+                    <image>https://example.com/foobar/screenshot-1.png</image>
+                </screenshot>
+                <!-- A second screenshot -->
+                <foo>bar</foo>
+                <screenshot>
+                    <image>https://example.com/foobar/screenshot-2.png</image>
+                </screenshot>
+            </screenshots>",
+        )?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = foobar_builder()
+            .screenshot(
+                ScreenshotBuilder::default()
+                    .image(
+                        ImageBuilder::new(Url::parse(
+                            "https://example.com/foobar/screenshot-1.png",
+                        )?)
+                        .build(),
+                    )
+                    .build(),
+            )
+            .screenshot(
+                ScreenshotBuilder::default()
+                    .set_default(false)
+                    .image(
+                        ImageBuilder::new(Url::parse(
+                            "https://example.com/foobar/screenshot-2.png",
+                        )?)
+                        .build(),
+                    )
                     .build(),
             )
             .build();
-
         assert_eq!(c1, c2);
         Ok(())
     }
 
     #[test]
-    #[cfg(feature = "test_json")]
-    fn serde_json_component() -> Result<(), Box<dyn Error>> {
+    fn unwrapped_screenshot_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <screenshot type='default'>
+                <caption>The main window</caption>
+                <image>https://example.com/foobar/screenshot-1.png</image>
+            </screenshot>",
+        )?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = foobar_builder()
+            .screenshot(
+                ScreenshotBuilder::default()
+                    .set_default(true)
+                    .caption(TranslatableString::with_default("The main window"))
+                    .image(
+                        ImageBuilder::new(Url::parse(
+                            "https://example.com/foobar/screenshot-1.png",
+                        )?)
+                        .build(),
+                    )
+                    .build(),
+            )
+            .build();
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_kudos_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <kudos>
+                <!-- Both known and unknown kudos -->
+                <kudo>FlatpakVerified</kudo>
+                <kudo>SomethingNew</kudo>
+            </kudos>",
+        )?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = foobar_builder()
+            .kudo(Kudo::FlatpakVerified)
+            .kudo(Kudo::Unknown("SomethingNew".into()))
+            .build();
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
+    #[test]
+    fn wrapped_component() -> Result<(), Box<dyn Error>> {
+        let xml = r"
+            <components version='0.14'>
+                <!-- A single component -->
+                <component>
+                    <id>com.example.foobar</id>
+                    <name>Foo Bar</name>
+                </component>
+            </components>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = foobar_builder().build();
+        assert_eq!(c1, c2);
+
+        let xml = r"
+            <components version='0.14'>
+                <component>
+                    <id>com.example.foo</id>
+                    <name>Foo</name>
+                </component>
+                <component>
+                    <id>com.example.bar</id>
+                    <name>Bar</name>
+                </component>
+            </components>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let error = Component::try_from(&element).unwrap_err();
+        assert!(matches!(error, ParseError::Other(tag, _) if tag == "components"));
+
+        let element = xmltree::Element::parse(r"<components version='0.14'/>".as_bytes())?;
+        let error = Component::try_from(&element).unwrap_err();
+        assert!(matches!(error, ParseError::MissingTag(tag) if tag == "component"));
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_content_ratings_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <content_rating type='oars-1.1'>
+                <content_attribute id='violence-cartoon'>mild</content_attribute>
+            </content_rating>
+            <content_rating type='oars-1.0'>
+                <content_attribute id='violence-cartoon'>intense</content_attribute>
+            </content_rating>",
+        )?;
+        let c = Component::try_from(&element)?;
+
+        assert_eq!(c.oars_version(), Some(ContentRatingVersion::Oars1_1));
+        let content_rating = c.content_rating.unwrap();
+        assert_eq!(
+            content_rating.attributes,
+            vec![ContentAttribute::ViolenceCartoon(ContentState::Mild)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn content_rating_with_comments_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <content_rating type='oars-1.1'>
+                <!-- Only cartoon characters are harmed -->
+                <content_attribute id='violence-cartoon'>mild</content_attribute>
+                <!-- The chat is moderated -->
+                <content_attribute id='social-chat'>moderate</content_attribute>
+            </content_rating>",
+        )?;
+        assert!(element
+            .get_child("content_rating")
+            .unwrap()
+            .children
+            .iter()
+            .any(|node| matches!(node, xmltree::XMLNode::Comment(_))));
+        let c = Component::try_from(&element)?;
+
+        let content_rating = c.content_rating.unwrap();
+        assert_eq!(
+            content_rating.attributes,
+            vec![
+                ContentAttribute::ViolenceCartoon(ContentState::Mild),
+                ContentAttribute::SocialChat(ContentState::Moderate)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn empty_provided_id_component() -> Result<(), Box<dyn Error>> {
+        for provided in ["<id/>", "<id>  </id>", "<id>org.gnome foo</id>"] {
+            let element = foobar_element(&format!("<provides>{}</provides>", provided))?;
+            assert!(Component::try_from(&element).is_err());
+        }
+
+        // Legacy ids are kept unless parsing in strict mode.
+        for provided in ["<id>foobar</id>", "<id>org..foobar</id>"] {
+            let element = foobar_element(&format!("<provides>{}</provides>", provided))?;
+            assert!(Component::try_from(&element).is_ok());
+            assert!(matches!(
+                Component::try_from_strict(&element),
+                Err(ParseError::InvalidValue(_, _, tag)) if tag == "id"
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn main_categories_component() {
+        let c = foobar_builder()
+            .category(Category::BlocksGame)
+            .category(Category::Game)
+            .build();
+        assert_eq!(c.main_categories(), vec![&Category::Game]);
+    }
+
+    #[test]
+    fn strict_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <requires>
+                <firmware compare='ge'>1.2.3</firmware>
+            </requires>",
+        )?;
+        assert!(Component::try_from(&element).is_ok());
+
+        let error = Component::try_from_strict(&element).unwrap_err();
+        assert!(
+            matches!(error, ParseError::Unsupported(feature) if feature == "<firmware> in <requires>")
+        );
+
+        let c = Component::try_from_strict(&xmltree::Element::parse(std::fs::File::open(
+            "./tests/app-org.gnome.design.Contrast.xml",
+        )?)?)?;
+        assert_eq!(c.id, "org.gnome.design.Contrast".into());
+        Ok(())
+    }
+
+    #[test]
+    fn partial_component() -> Result<(), Box<dyn Error>> {
+        let xml = r"
+            <component type='desktop-application'>
+                <metadata_license>CC0-1.0</metadata_license>
+                <name>Foo Bar</name>
+                <summary>A foo bar</summary>
+            </component>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        assert!(matches!(
+            Component::try_from(&element),
+            Err(ParseError::MissingTag(tag)) if tag == "id"
+        ));
+
+        let (c, warnings) = Component::try_from_partial(&element)?;
+        assert_eq!(warnings, vec![ValidationWarning::MissingId]);
+        assert_eq!(c.id, "".into());
+        assert_eq!(c.name, TranslatableString::with_default("Foo Bar"));
+        assert_eq!(c.metadata_license, Some("CC0-1.0".into()));
+
+        let xml = r"
+            <components version='0.14'>
+                <component>
+                    <name>Foo Bar</name>
+                </component>
+            </components>";
+        let (c, warnings) = Component::try_from_partial(&xmltree::Element::parse(xml.as_bytes())?)?;
+        assert_eq!(warnings, vec![ValidationWarning::MissingId]);
+        assert_eq!(c.id, "".into());
+
+        let (c, warnings) = Component::try_from_partial(&xmltree::Element::parse(
+            std::fs::File::open("./tests/addon.xml")?,
+        )?)?;
+        assert!(warnings.is_empty());
+        assert_eq!(c.id, "org.gnome.gedit_code_assistance".into());
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_description_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <description><p>First description</p></description>
+            <description xml:lang='de'><p>Beschreibung</p></description>
+            <description><p>Second description</p></description>",
+        )?;
+        assert_eq!(
+            Component::validate_element(&element)?,
+            vec![ValidationWarning::DuplicateDescription("C".into())]
+        );
+
+        let c = Component::try_from(&element)?;
+        assert!(c.validate().is_empty());
+        assert_eq!(
+            c.description,
+            Some(
+                MarkupTranslatableString::with_default("<p>Second description</p>")
+                    .and_locale("de", "<p>Beschreibung</p>")
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn screenshots_without_type_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <screenshots>
+                <screenshot>
+                    <image>https://example.com/foobar/screenshot-1.png</image>
+                </screenshot>
+                <screenshot>
+                    <image>https://example.com/foobar/screenshot-2.png</image>
+                </screenshot>
+            </screenshots>",
+        )?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = foobar_builder()
+            .screenshot(
+                ScreenshotBuilder::default()
+                    .image(
+                        ImageBuilder::new(Url::parse(
+                            "https://example.com/foobar/screenshot-1.png",
+                        )?)
+                        .build(),
+                    )
+                    .build(),
+            )
+            .screenshot(
+                ScreenshotBuilder::default()
+                    .set_default(false)
+                    .image(
+                        ImageBuilder::new(Url::parse(
+                            "https://example.com/foobar/screenshot-2.png",
+                        )?)
+                        .build(),
+                    )
+                    .build(),
+            )
+            .build();
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_screenshot_type_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <screenshots>
+                <screenshot type='default'>
+                    <image>https://example.com/foobar/screenshot-1.png</image>
+                </screenshot>
+                <screenshot type='hero'>
+                    <image type='banner'>https://example.com/foobar/screenshot-2.png</image>
+                </screenshot>
+            </screenshots>",
+        )?;
+        let c = Component::try_from(&element)?;
+        assert!(!c.screenshots[1].is_default);
+        assert_eq!(
+            Component::validate_element(&element)?,
+            vec![
+                ValidationWarning::UnknownScreenshotType("hero".into()),
+                ValidationWarning::UnknownImageType("banner".into())
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn namespaced_component() -> Result<(), Box<dyn Error>> {
+        let xml = r"
+            <component xmlns='https://specifications.freedesktop.org/metainfo/1.0' type='desktop-application'>
+                <id>com.example.foobar</id>
+                <name>Foo Bar</name>
+                <name xml:lang='de'>Foo Balken</name>
+                <categories>
+                    <category>Utility</category>
+                </categories>
+            </component>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = ComponentBuilder::default()
+            .kind(ComponentKind::DesktopApplication)
+            .id("com.example.foobar".into())
+            .name(TranslatableString::with_default("Foo Bar").and_locale("de", "Foo Balken"))
+            .category(Category::Utility)
+            .build();
+        assert_eq!(c1, c2);
+
+        let xml = r"
+            <as:component xmlns:as='https://specifications.freedesktop.org/metainfo/1.0'>
+                <as:id>com.example.foobar</as:id>
+                <as:name>Foo Bar</as:name>
+            </as:component>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let c = Component::try_from(&element)?;
+        assert_eq!(c.id, "com.example.foobar".into());
+        assert_eq!(c.name, TranslatableString::with_default("Foo Bar"));
+        Ok(())
+    }
+
+    #[test]
+    fn relations_with_comments_component() -> Result<(), Box<dyn Error>> {
+        use crate::{Control, Requirement};
+
+        let element = foobar_element(
+            r"
+            <requires>
+                <!-- The app is adaptive and works on phones too -->
+                <display_length compare='ge'>360</display_length>
+            </requires>
+            <recommends>
+                <!-- Touch screens are handled as well -->
+                <control>touch</control>
+            </recommends>
+            <supports>
+                <!-- Keyboard navigation is fully supported -->
+                <control>keyboard</control>
+            </supports>",
+        )?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = foobar_builder()
+            .requires(Requirement::display_length(
+                crate::DisplayLengthValue::Value(360),
+            ))
+            .recommends(Requirement::control(Control::Touch))
+            .supports(Requirement::control(Control::Keyboard))
+            .build();
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
+    #[test]
+    fn legacy_categories_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <appcategories>
+                <appcategory>Game</appcategory>
+                <appcategory>BlocksGame</appcategory>
+                <appcategory>Foo</appcategory>
+            </appcategories>",
+        )?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = foobar_builder()
+            .category(Category::Game)
+            .category(Category::BlocksGame)
+            .category(Category::Unknown("Foo".into()))
+            .build();
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
+    #[test]
+    fn provides_binary_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <provides>
+                <binary>usr/bin/foobar</binary>
+                <binary>foobar-cli</binary>
+            </provides>",
+        )?;
+        let c = Component::try_from(&element)?;
+
+        assert_eq!(c.provides[0].binary_name(), Some("foobar"));
+        assert!(c.provides_binary("foobar"));
+        assert!(c.provides_binary("usr/bin/foobar"));
+        assert!(c.provides_binary("foobar-cli"));
+        assert!(!c.provides_binary("bin"));
+        Ok(())
+    }
+
+    #[test]
+    fn all_mimetypes_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <mimetypes>
+                <mimetype>text/html</mimetype>
+                <mimetype>text/xml</mimetype>
+            </mimetypes>
+            <provides>
+                <mediatype>text/xml</mediatype>
+                <mediatype>application/xhtml+xml</mediatype>
+                <binary>foobar</binary>
+            </provides>",
+        )?;
+        let c = Component::try_from(&element)?;
+
+        assert_eq!(
+            c.all_mimetypes(),
+            vec!["text/html", "text/xml", "application/xhtml+xml"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn desktop_id_component() -> Result<(), Box<dyn Error>> {
+        let c = Component::from_path("./tests/desktop.xml".into())?;
+        assert_eq!(
+            c.desktop_id().as_deref(),
+            Some("org.gnome.gnome-power-statistics.desktop")
+        );
+
+        let c = ComponentBuilder::default()
+            .kind(ComponentKind::DesktopApplication)
+            .id("com.example.foobar".into())
+            .name(TranslatableString::with_default("Foo Bar"))
+            .build();
+        assert_eq!(
+            c.desktop_id().as_deref(),
+            Some("com.example.foobar.desktop")
+        );
+
+        let c = ComponentBuilder::default()
+            .kind(ComponentKind::DesktopApplication)
+            .id("foobar.desktop".into())
+            .name(TranslatableString::with_default("Foo Bar"))
+            .build();
+        assert_eq!(c.desktop_id().as_deref(), Some("foobar.desktop"));
+
+        let c = Component::from_path("./tests/addon.xml".into())?;
+        assert_eq!(c.desktop_id(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn service_launchable_component() -> Result<(), Box<dyn Error>> {
+        let xml = r"
+            <component type='service'>
+                <id>org.freedesktop.fwupd</id>
+                <name>fwupd</name>
+                <launchable type='service'>fwupd.service</launchable>
+                <launchable type='cockpit-manifest'>fwupd</launchable>
+            </component>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let c = Component::try_from(&element)?;
+        assert_eq!(c.service_launchable(), Some("fwupd.service"));
+        assert_eq!(c.cockpit_manifest(), Some("fwupd"));
+        assert_eq!(c.desktop_id(), None);
+
+        let c = Component::from_path("./tests/desktop.xml".into())?;
+        assert_eq!(c.service_launchable(), None);
+        assert_eq!(c.cockpit_manifest(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn desktop_entry_component() -> Result<(), Box<dyn Error>> {
+        let c = Component::from_path("./tests/desktop.xml".into())?;
+        let entry = c.to_desktop_entry(&["de"]);
+        assert_eq!(
+            entry,
+            "[Desktop Entry]\nType=Application\nName=Power Statistics\nComment=Observe power management\n"
+        );
+
+        let c = ComponentBuilder::default()
+            .id("com.example.foobar".into())
+            .kind(ComponentKind::DesktopApplication)
+            .name(TranslatableString::with_default("Foo Bar").and_locale("de", "Foo Balken"))
+            .summary(TranslatableString::with_default("A foo bar"))
+            .category(Category::Development)
+            .category(Category::Unknown("X-Foo".into()))
+            .mimetype("text/plain")
+            .keywords(
+                TranslatableList::with_default(vec!["foo", "bar"])
+                    .and_locale("de", vec!["balken"])
+                    .and_locale("fr", vec!["barre"]),
+            )
+            .build();
+        let entry = c.to_desktop_entry(&["de", "pt_BR"]);
+        assert_eq!(
+            entry.lines().collect::<Vec<_>>(),
+            vec![
+                "[Desktop Entry]",
+                "Type=Application",
+                "Name=Foo Bar",
+                "Name[de]=Foo Balken",
+                "Comment=A foo bar",
+                "Categories=Development;X-Foo;",
+                "MimeType=text/plain;",
+                "Keywords=foo;bar;",
+                "Keywords[de]=balken;",
+            ]
+        );
+
+        let c = ComponentBuilder::default()
+            .id("com.example.foobar".into())
+            .kind(ComponentKind::ConsoleApplication)
+            .name(TranslatableString::with_default("Foo\nBar"))
+            .summary(TranslatableString::with_default("C:\\foo\tbar"))
+            .category(Category::Unknown("X-Foo;Bar".into()))
+            .keywords(TranslatableList::with_default(vec!["foo;bar", "baz"]))
+            .build();
+        assert_eq!(
+            c.to_desktop_entry(&[]).lines().collect::<Vec<_>>(),
+            vec![
+                "[Desktop Entry]",
+                "Type=Application",
+                "Name=Foo\\nBar",
+                "Comment=C:\\\\foo\\tbar",
+                "Categories=X-Foo\\;Bar;",
+                "Keywords=foo\\;bar;baz;",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_local_icons_component() {
+        let mut c = foobar_builder()
+            .icon(Icon::Cached {
+                path: "64x64/foobar.png".into(),
+                width: Some(64),
+                height: Some(64),
+                scale: None,
+            })
+            .icon(Icon::Local {
+                path: "/usr/share/icons/foobar.png".into(),
+                width: None,
+                height: None,
+                scale: None,
+            })
+            .icon(Icon::Stock("foobar".into()))
+            .build();
+        c.resolve_local_icons(std::path::Path::new("/usr/share/app-info/icons/flathub"));
+
+        assert_eq!(
+            c.icons,
+            vec![
+                Icon::Cached {
+                    path: "/usr/share/app-info/icons/flathub/64x64/foobar.png".into(),
+                    width: Some(64),
+                    height: Some(64),
+                    scale: None,
+                },
+                Icon::Local {
+                    path: "/usr/share/icons/foobar.png".into(),
+                    width: None,
+                    height: None,
+                    scale: None,
+                },
+                Icon::Stock("foobar".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn urls_of_kind_component() -> Result<(), Box<dyn Error>> {
+        use crate::enums::ProjectUrlKind;
+
+        let element = foobar_element(
+            r"
+            <url type='homepage'>https://example.com</url>
+            <url type='bugtracker'>https://example.com/issues</url>
+            <url>https://foobar.example.org</url>",
+        )?;
+        let c = Component::try_from(&element)?;
+
+        assert_eq!(
+            c.urls_of_kind(ProjectUrlKind::Homepage),
+            vec![
+                &Url::parse("https://example.com")?,
+                &Url::parse("https://foobar.example.org")?
+            ]
+        );
+        assert_eq!(
+            c.url(ProjectUrlKind::Homepage),
+            Some(&Url::parse("https://example.com")?)
+        );
+        assert_eq!(
+            c.url(ProjectUrlKind::BugTracker),
+            Some(&Url::parse("https://example.com/issues")?)
+        );
+        assert!(c.urls_of_kind(ProjectUrlKind::Donation).is_empty());
+        assert_eq!(c.url(ProjectUrlKind::Help), None);
+        Ok(())
+    }
+
+    #[test]
+    fn strip_component() -> Result<(), Box<dyn Error>> {
+        use crate::StripOptions;
+
+        let c = Component::from_path("./tests/app-org.gnome.design.Contrast.xml".into())?;
+        assert!(!c.releases.is_empty());
+
+        let without_releases = c.clone_without_releases();
+        assert!(without_releases.releases.is_empty());
+        assert_eq!(without_releases.screenshots, c.screenshots);
+
+        let mut stripped = c.clone();
+        stripped.strip(StripOptions::all());
+        assert!(stripped.releases.is_empty());
+        assert!(stripped.screenshots.is_empty());
+        assert!(stripped.description.is_none());
+        assert!(format!("{:?}", stripped).len() < format!("{:?}", without_releases).len());
+
+        assert_eq!(stripped.id, c.id);
+        assert_eq!(stripped.name, c.name);
+        assert_eq!(stripped.summary, c.summary);
+        assert_eq!(stripped.keywords, c.keywords);
+        assert_eq!(stripped.categories, c.categories);
+
+        let mut unchanged = c.clone();
+        unchanged.strip(StripOptions::default());
+        assert_eq!(unchanged, c);
+        Ok(())
+    }
+
+    #[test]
+    fn sizes_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <releases order='ascending'>
+                <release version='1.0'>
+                    <size type='download'>1024</size>
+                    <size type='installed'>4096</size>
+                </release>
+                <release version='1.1'>
+                    <size type='installed'>8192</size>
+                </release>
+            </releases>",
+        )?;
+        let c = Component::try_from(&element)?;
+
+        assert_eq!(c.latest_release().unwrap().version, "1.1");
+        assert_eq!(c.installed_size(), Some(8192));
+        assert_eq!(c.download_size(), None);
+
+        let element = foobar_element(
+            r"
+            <releases>
+                <release version='1.2'>
+                    <size type='download'>2048</size>
+                    <size type='installed'>16384</size>
+                </release>
+                <release version='1.1'>
+                    <size type='installed'>8192</size>
+                </release>
+                <release version='1.0'>
+                    <size type='download'>1024</size>
+                    <size type='installed'>4096</size>
+                </release>
+            </releases>",
+        )?;
+        let c = Component::try_from(&element)?;
+
+        assert_eq!(c.latest_release().unwrap().version, "1.2");
+        assert_eq!(c.installed_size(), Some(16384));
+        assert_eq!(c.download_size(), Some(2048));
+
+        let c = Component::from_path("./tests/os.xml".into())?;
+        assert_eq!(c.latest_release().unwrap().version, "10.0");
+        assert_eq!(c.installed_size(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn external_releases_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <releases type='external' url='https://example.com/foobar.releases.xml'/>",
+        )?;
+        let mut c = Component::try_from(&element)?;
+        assert_eq!(
+            c.releases_url,
+            Some(Url::parse("https://example.com/foobar.releases.xml")?)
+        );
+        assert!(c.releases.is_empty());
+
+        c.load_external_releases(|url| {
+            assert_eq!(url.as_str(), "https://example.com/foobar.releases.xml");
+            Ok(r"
+                <releases>
+                    <release version='1.1' date='2020-02-12' />
+                    <release version='1.0' date='2020-01-12' />
+                </releases>"
+                .as_bytes()
+                .to_vec())
+        })?;
+        assert_eq!(c.releases.len(), 2);
+        assert_eq!(c.latest_release().unwrap().version, "1.1");
+
+        let error = c
+            .load_external_releases(|_| Err(std::io::ErrorKind::NotFound.into()))
+            .unwrap_err();
+        assert!(matches!(error, ParseError::IOError(_)));
+
+        let mut c = Component::from_path("./tests/desktop.xml".into())?;
+        c.load_external_releases(|_| unreachable!())?;
+        assert_eq!(c.releases.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn bogus_provides_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <provides>
+                <binary>foobar</binary>
+                <icon type='stock'>foobar</icon>
+                <mediatype>text/plain</mediatype>
+            </provides>",
+        )?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = foobar_builder()
+            .provide(Provide::Binary("foobar".into()))
+            .provide(Provide::Unknown {
+                tag: "icon".into(),
+                value: "foobar".into(),
+            })
+            .provide(Provide::MediaType("text/plain".into()))
+            .build();
+        assert_eq!(c1, c2);
+
+        assert!(matches!(
+            Component::try_from_strict(&element),
+            Err(ParseError::Unsupported(f)) if f == "<icon> in <provides>"
+        ));
+
+        let element = foobar_element(
+            r"
+            <provides>
+                <somethingnew>x</somethingnew>
+            </provides>",
+        )?;
+        let c = Component::try_from(&element)?;
+        assert_eq!(
+            c.provides,
+            vec![Provide::Unknown {
+                tag: "somethingnew".into(),
+                value: "x".into(),
+            }]
+        );
+        assert_eq!(c.provides[0].to_string(), "somethingnew:x");
+        assert!(Component::try_from_strict(&element).is_err());
+
+        let (_, warnings) = Component::try_from_partial(&element)?;
+        assert_eq!(
+            warnings,
+            vec![ValidationWarning::UnknownProvide("somethingnew".into())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn mixed_provides_order_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <provides>
+                <dbus type='session'>com.example.FooBar</dbus>
+                <mediatype>text/plain</mediatype>
+                <binary>foobar</binary>
+                <dbus type='system'>com.example.FooBar.Daemon</dbus>
+                <mediatype>text/markdown</mediatype>
+                <binary>foobar-cli</binary>
+            </provides>",
+        )?;
+        let c = Component::try_from(&element)?;
+        assert_eq!(
+            c.provides,
+            vec![
+                Provide::DBus("com.example.FooBar".into()),
+                Provide::MediaType("text/plain".into()),
+                Provide::Binary("foobar".into()),
+                Provide::DBus("com.example.FooBar.Daemon".into()),
+                Provide::MediaType("text/markdown".into()),
+                Provide::Binary("foobar-cli".into()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn release_order_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <releases order='ascending'>
+                <release version='1.0' />
+                <release version='1.1' />
+            </releases>",
+        )?;
+        let c = Component::try_from(&element)?;
+        assert_eq!(c.release_order, ReleaseOrder::Ascending);
+
+        let c = Component::from_path("./tests/os.xml".into())?;
+        assert_eq!(c.release_order, ReleaseOrder::Inverse);
+
+        let element = foobar_element(
+            r"
+            <releases order='random' />",
+        )?;
+        assert!(matches!(
+            Component::try_from(&element),
+            Err(ParseError::InvalidValue(v, _, _)) if v == "random"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_kind_component() -> Result<(), Box<dyn Error>> {
+        let xml = r"
+            <component type='mimetype-handler'>
+                <id>com.example.foobar</id>
+                <name>Foo Bar</name>
+            </component>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let c = Component::try_from(&element)?;
+
+        assert_eq!(c.kind, ComponentKind::Unknown("mimetype-handler".into()));
+        assert_eq!(c.raw_kind(), Some("mimetype-handler"));
+
+        let c = Component::from_path("./tests/addon.xml".into())?;
+        assert_eq!(c.raw_kind(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn whitespace_developer_name() -> Result<(), Box<dyn Error>> {
+        let xml = r"
+            <component>
+                <id>com.example.foobar</id>
+                <name>
+                    Foo   Bar
+                </name>
+                <developer_name>
+                    FooBar Team
+                </developer_name>
+            </component>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let c = Component::try_from(&element)?;
+
+        assert_eq!(
+            c.developer_name.and_then(TranslatableString::into_default),
+            Some("FooBar Team".to_string())
+        );
+        assert_eq!(c.name.into_default(), Some("Foo Bar".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn empty_categories_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <categories>
+                <category>Game</category>
+                <category/>
+                <category type='legacy'>   </category>
+                <category type='legacy'> BlocksGame </category>
+            </categories>",
+        )?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = foobar_builder()
+            .category(Category::Game)
+            .category(Category::BlocksGame)
+            .build();
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_categories_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <categories>
+                <category>Utility</category>
+                <category>Development</category>
+                <category>Utility</category>
+            </categories>
+            <categories>
+                <category>Development</category>
+            </categories>",
+        )?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = foobar_builder()
+            .category(Category::Utility)
+            .category(Category::Development)
+            .build();
+        assert_eq!(c1, c2);
+        assert!(c1.has_category(&Category::Utility));
+        assert!(!c1.has_category(&Category::Game));
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_keywords_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <keywords>
+                <keyword>Color</keyword>
+                <keyword xml:lang='de'>Farbe</keyword>
+                <keyword>Contrast</keyword>
+                <keyword>Color</keyword>
+                <keyword xml:lang='de'>Farbe</keyword>
+            </keywords>",
+        )?;
+        let c1 = Component::try_from(&element)?;
+
+        let c2 = foobar_builder()
+            .keywords(
+                TranslatableList::with_default(vec!["Color", "Contrast"])
+                    .and_locale("de", vec!["Farbe"]),
+            )
+            .build();
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
+    #[test]
+    fn machine_translated_keywords_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <keywords>
+                <keyword>Color</keyword>
+                <keyword xml:lang='de'>Farbe</keyword>
+                <keyword xml:lang='de' translated='yes'>Kontrast</keyword>
+                <keyword xml:lang='fr' translated='no'>Couleur</keyword>
+            </keywords>",
+        )?;
+        let c = Component::try_from(&element)?;
+
+        assert_eq!(
+            c.keywords,
+            Some(
+                TranslatableList::with_default(vec!["Color"])
+                    .and_locale("de", vec!["Farbe", "Kontrast"])
+                    .and_locale("fr", vec!["Couleur"])
+            )
+        );
+        assert_eq!(
+            c.machine_translated_keywords,
+            Some(TranslatableList::default().and_locale("de", vec!["Kontrast"]))
+        );
+
+        let c = Component::from_path("./tests/app-org.gnome.design.Contrast.xml".into())?;
+        assert!(c.machine_translated_keywords.is_none());
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn runtime_component() -> Result<(), Box<dyn Error>> {
+        let c1: Component = Component::from_path("./tests/runtime.xml".into())?;
+
+        let c2 = ComponentBuilder::default()
+            .id("org.freedesktop.Platform".into())
+            .name(TranslatableString::with_default("Freedesktop Platform"))
+            .kind(ComponentKind::Runtime)
+            .metadata_license("FSFAP".into())
+            .project_license("LicenseRef-free=https://freedesktop-sdk.gitlab.io/".into())
+            .summary(TranslatableString::with_default(
+                "Basic libraries to run Linux desktop applications",
+            ))
+            .description(
+                MarkupTranslatableString::with_default("<p>\n      The Freedesktop Platform is a runtime that contains the most basic libraries\n      and files needed to run a Linux desktop application.\n        </p>")
+            )
+            .url(ProjectUrl::Homepage(
+                Url::parse("https://freedesktop-sdk.gitlab.io/")?,
+            ))
+            .release(ReleaseBuilder::new("10.0").build())
+            .release(
+                ReleaseBuilder::new("9.0")
+                    .date(Utc.with_ymd_and_hms(2020, 1, 12, 0, 0, 0).unwrap())
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
+    #[test]
+    fn validate_component_videos() -> Result<(), Box<dyn Error>> {
+        let c1 = foobar_builder()
+            .screenshot(
+                ScreenshotBuilder::default()
+                    .video(
+                        VideoBuilder::new(Url::parse("https://example.com/screencast.mkv")?)
+                            .container("mkv")
+                            .codec("av1")
+                            .build(),
+                    )
+                    .video(
+                        VideoBuilder::new(Url::parse("https://example.com/screencast.mp4")?)
+                            .container("mp4")
+                            .codec("h264")
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(
+            c1.validate(),
+            vec![ValidationWarning::UnsupportedVideo(Url::parse(
+                "https://example.com/screencast.mp4"
+            )?)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn validate_component_type() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <launchable type='desktop-id'>com.example.foobar.desktop</launchable>",
+        )?;
+        let c = Component::try_from(&element)?;
+        assert_eq!(c.kind, ComponentKind::Generic);
+        assert_eq!(c.validate(), vec![ValidationWarning::MissingComponentType]);
+
+        let c = Component::from_path("./tests/desktop.xml".into())?;
+        assert!(c.validate().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_releases_component() -> Result<(), Box<dyn Error>> {
+        let element = foobar_element(
+            r"
+            <releases>
+                <release version='0.0.1' date='2020-01-12' />
+                <release version='0.0.2' date='2020-02-12' />
+                <release version='0.0.1' date='2020-03-12' />
+            </releases>",
+        )?;
+        let c = Component::try_from(&element)?;
+
+        assert_eq!(
+            c.validate(),
+            vec![ValidationWarning::DuplicateReleaseVersion("0.0.1".into())]
+        );
+        #[cfg(feature = "chrono")]
+        assert_eq!(
+            c.release("0.0.2").and_then(|r| r.date),
+            Some(Utc.with_ymd_and_hms(2020, 2, 12, 0, 0, 0).unwrap())
+        );
+        assert!(c.release("1.0.0").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn merge_component() -> Result<(), Box<dyn Error>> {
+        let mut c1 = ComponentBuilder::default()
+            .id("org.gnome.design.Contrast".into())
+            .name(TranslatableString::with_default("Contrast"))
+            .language(LanguageBuilder::new("cs").percentage(100).build())
+            .release(ReleaseBuilder::new("0.0.3").build())
+            .build();
+
+        let c2 = ComponentBuilder::default()
+            .id("org.gnome.design.Contrast".into())
+            .name(TranslatableString::with_default("Kontrast"))
+            .icon(Icon::Stock("contrast".into()))
+            .screenshot(
+                ScreenshotBuilder::default()
+                    .image(ImageBuilder::new(Url::parse("https://example.org/main.png")?).build())
+                    .build(),
+            )
+            .language(LanguageBuilder::new("cs").percentage(50).build())
+            .language(LanguageBuilder::new("da").percentage(93).build())
+            .release(
+                ReleaseBuilder::new("0.0.3")
+                    .kind(ReleaseKind::Development)
+                    .build(),
+            )
+            .release(ReleaseBuilder::new("0.0.2").build())
+            .build();
+
+        c1.merge(&c2);
+
+        let c3 = ComponentBuilder::default()
+            .id("org.gnome.design.Contrast".into())
+            .name(TranslatableString::with_default("Contrast"))
+            .icon(Icon::Stock("contrast".into()))
+            .screenshot(
+                ScreenshotBuilder::default()
+                    .image(ImageBuilder::new(Url::parse("https://example.org/main.png")?).build())
+                    .build(),
+            )
+            .language(LanguageBuilder::new("cs").percentage(100).build())
+            .language(LanguageBuilder::new("da").percentage(93).build())
+            .release(ReleaseBuilder::new("0.0.3").build())
+            .release(ReleaseBuilder::new("0.0.2").build())
+            .build();
+        assert_eq!(c1, c3);
+
+        // The newest release of `other` is listed according to the order.
+        let mut c1 = ComponentBuilder::default()
+            .id("org.gnome.design.Contrast".into())
+            .name(TranslatableString::with_default("Contrast"))
+            .release(ReleaseBuilder::new("0.0.3").build())
+            .release(ReleaseBuilder::new("0.0.1").build())
+            .build();
+        let c2 = ComponentBuilder::default()
+            .id("org.gnome.design.Contrast".into())
+            .name(TranslatableString::with_default("Contrast"))
+            .release(ReleaseBuilder::new("0.0.4").build())
+            .release(ReleaseBuilder::new("0.0.2").build())
+            .build();
+        c1.merge(&c2);
+        let versions = c1
+            .releases
+            .iter()
+            .map(|r| r.version.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(versions, vec!["0.0.4", "0.0.3", "0.0.2", "0.0.1"]);
+        assert_eq!(c1.latest_release().unwrap().version, "0.0.4");
+
+        let mut c1 = ComponentBuilder::default()
+            .id("org.gnome.design.Contrast".into())
+            .name(TranslatableString::with_default("Contrast"))
+            .release_order(ReleaseOrder::Ascending)
+            .release(ReleaseBuilder::new("0.0.1").build())
+            .release(ReleaseBuilder::new("0.0.3").build())
+            .build();
+        c1.merge(&c2);
+        let versions = c1
+            .releases
+            .iter()
+            .map(|r| r.version.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(versions, vec!["0.0.1", "0.0.2", "0.0.3", "0.0.4"]);
+        assert_eq!(c1.latest_release().unwrap().version, "0.0.4");
+        Ok(())
+    }
+
+    #[test]
+    fn display_length_component() -> Result<(), Box<dyn Error>> {
+        use crate::{DisplayLengthValue, Rel, Side};
+
+        let c = Component::from_path("./tests/app-org.gnome.design.Contrast.xml".into())?;
+
+        let display_length = c.required_display_length().unwrap();
+        assert_eq!(display_length.compare, Rel::Ge);
+        assert_eq!(display_length.value, DisplayLengthValue::Value(360));
+        assert_eq!(display_length.side, Side::Shortest);
+        assert!(c.supported_display_lengths().is_empty());
+
+        let c = Component::from_path("./tests/desktop.xml".into())?;
+        assert!(c.required_display_length().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn controls_component() -> Result<(), Box<dyn Error>> {
+        use crate::{Control, Requirement};
+
+        let c = Component::from_path("./tests/app-org.gnome.design.Contrast.xml".into())?;
+        assert_eq!(c.supported_controls(), vec![Control::Keyboard]);
+        assert!(c.required_controls().is_empty());
+        assert!(c.recommended_controls().is_empty());
+
+        let c = foobar_builder()
+            .requires(Requirement::Control(Control::Pointing))
+            .recommends(Requirement::Control(Control::Touch))
+            .recommends(Requirement::Control(Control::Gamepad))
+            .build();
+        assert_eq!(c.required_controls(), vec![Control::Pointing]);
+        assert_eq!(
+            c.recommended_controls(),
+            vec![Control::Touch, Control::Gamepad]
+        );
+        assert!(c.supported_controls().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "test_json")]
+    fn serde_json_component() -> Result<(), Box<dyn Error>> {
         let file = std::fs::File::open("./tests/app-com.github.utsushi.Utsushi.json")?;
         let c: Component = serde_json::from_reader(&file)?;
 
@@ -670,6 +2751,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "json")]
+    fn json_path_component() -> Result<(), Box<dyn Error>> {
+        let c1 = Component::from_json_path("./tests/app-com.github.utsushi.Utsushi.json".into())?;
+
+        let path = std::env::temp_dir().join("appstream-json-path-component.json");
+        c1.to_json_path(path.clone())?;
+        let c2 = Component::from_json_path(path.clone())?;
+        std::fs::remove_file(path)?;
+
+        assert_eq!(c1, c2);
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
     #[test]
     fn contrast_metainfo_component() -> Result<(), Box<dyn Error>> {
         use crate::{AppId, Control, DisplayLength, DisplayLengthValue, Requirement};
@@ -788,19 +2884,19 @@ mod tests {
             })
             .release(
                 ReleaseBuilder::new("0.0.3")
-                    .date(Utc.datetime_from_str("1582329600", "%s")?)
+                    .date(Utc.timestamp_opt(1582329600, 0).unwrap())
                     .description(MarkupTranslatableString::with_default("<p>Stylesheet fixes</p><p>Translations updates</p>"))
                     .build()
             )
             .release(
                 ReleaseBuilder::new("0.0.2")
-                    .date(Utc.datetime_from_str("1566691200", "%s")?)
+                    .date(Utc.timestamp_opt(1566691200, 0).unwrap())
                     .description(MarkupTranslatableString::with_default("<p>Translations updates</p>"))
                     .build()
             )
             .release(
                 ReleaseBuilder::new("0.0.1")
-                    .date(Utc.datetime_from_str("1565136000", "%s")?)
+                    .date(Utc.timestamp_opt(1565136000, 0).unwrap())
                     .description(MarkupTranslatableString::with_default("<p>First release of Contrast</p>"))
                     .build()
             )
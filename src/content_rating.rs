@@ -14,3 +14,33 @@ pub struct ContentRating {
     /// A list of attributes that defines the OARS.
     pub attributes: Vec<ContentAttribute>,
 }
+
+impl ContentRating {
+    /// The minimum age suitable for the component, the highest
+    /// `ContentAttribute::minimum_age` of its attributes or `0` if it has
+    /// none.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::{
+    ///     enums::{ContentAttribute, ContentRatingVersion, ContentState},
+    ///     ContentRating,
+    /// };
+    ///
+    /// let content_rating = ContentRating {
+    ///     version: ContentRatingVersion::Oars1_1,
+    ///     attributes: vec![
+    ///         ContentAttribute::ViolenceCartoon(ContentState::Mild),
+    ///         ContentAttribute::SocialChat(ContentState::Intense),
+    ///     ],
+    /// };
+    /// assert_eq!(content_rating.minimum_age(), 13);
+    /// ```
+    pub fn minimum_age(&self) -> u32 {
+        self.attributes
+            .iter()
+            .map(|a| a.minimum_age())
+            .max()
+            .unwrap_or(0)
+    }
+}
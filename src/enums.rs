@@ -1,7 +1,7 @@
 use std::{
     cmp::{Ord, Ordering},
     fmt,
-    path::PathBuf,
+    path::{Path, PathBuf},
     str::FromStr,
 };
 
@@ -27,8 +27,7 @@ pub enum ArtifactKind {
     Binary,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq)]
-#[serde(tag = "type", rename_all = "lowercase")]
+#[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 /// Indicates that the software is available via a 3rd-party application
 /// installer. See [\<bundle\/\>](https://www.freedesktop.org/software/appstream/docs/chap-CollectionData.html#tag-ct-bundle).
@@ -37,7 +36,6 @@ pub enum Bundle {
     Limba(String),
     /// A [Flatpak](https://flatpak.org/) bundle.
     Flatpak {
-        #[serde(skip_serializing_if = "Option::is_none")]
         /// The required runtime to run the application.
         runtime: Option<String>,
         /// The SDK used to build the application.
@@ -48,7 +46,12 @@ pub enum Bundle {
     /// An [AppImage](https://appimage.org/) bundle.
     AppImage(String),
     /// A [Snap](https://snapcraft.io/) bundle.
-    Snap(String),
+    Snap {
+        /// The snap name.
+        id: String,
+        /// The channel to install the snap from, e.g. `stable`.
+        channel: Option<String>,
+    },
     /// Plain and possibly compressed tarballs.
     Tarball(String),
 }
@@ -83,9 +86,12 @@ impl Serialize for Bundle {
                 bundle_map.serialize_entry("type", "appimage")?;
                 bundle_map.serialize_entry("id", id)?;
             }
-            Bundle::Snap(id) => {
+            Bundle::Snap { id, channel } => {
                 bundle_map.serialize_entry("type", "snap")?;
                 bundle_map.serialize_entry("id", id)?;
+                if let Some(channel) = channel {
+                    bundle_map.serialize_entry("channel", channel)?;
+                }
             }
             Bundle::Tarball(id) => {
                 bundle_map.serialize_entry("type", "tarball")?;
@@ -97,7 +103,80 @@ impl Serialize for Bundle {
     }
 }
 
-#[derive(Clone, Debug, AsRefStr, EnumString, Display, Serialize, Deserialize, PartialEq)]
+impl<'de> Deserialize<'de> for Bundle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BundleVisitor;
+
+        impl<'de> Visitor<'de> for BundleVisitor {
+            type Value = Bundle;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map representing a bundle")
+            }
+
+            fn visit_map<M>(self, mut access: M) -> Result<Self::Value, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let mut kind = None;
+                let mut id = None;
+                let mut runtime = None;
+                let mut sdk = None;
+                let mut channel = None;
+
+                while let Some(key) = access.next_key::<String>()? {
+                    match &*key {
+                        "type" => {
+                            kind = Some(access.next_value::<String>()?);
+                        }
+                        "id" | "reference" => {
+                            id = Some(access.next_value::<String>()?);
+                        }
+                        "runtime" => {
+                            runtime = access.next_value::<Option<String>>()?;
+                        }
+                        "sdk" => {
+                            sdk = access.next_value::<Option<String>>()?;
+                        }
+                        "channel" => {
+                            channel = access.next_value::<Option<String>>()?;
+                        }
+                        _ => {
+                            access.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                let kind = kind.ok_or_else(|| de::Error::missing_field("type"))?;
+                let id = id.ok_or_else(|| de::Error::missing_field("id"))?;
+
+                match kind.as_ref() {
+                    "limba" => Ok(Bundle::Limba(id)),
+                    "flatpak" => Ok(Bundle::Flatpak {
+                        runtime,
+                        sdk,
+                        reference: id,
+                    }),
+                    "appimage" => Ok(Bundle::AppImage(id)),
+                    "snap" => Ok(Bundle::Snap { id, channel }),
+                    "tarball" => Ok(Bundle::Tarball(id)),
+                    e => Err(de::Error::invalid_value(
+                        de::Unexpected::Str(e),
+                        &"expected a type of limba, flatpak, appimage, snap or tarball",
+                    )),
+                }
+            }
+        }
+        deserializer.deserialize_map(BundleVisitor)
+    }
+}
+
+#[derive(
+    Clone, Debug, AsRefStr, EnumString, Display, Serialize, Deserialize, PartialEq, Eq, Hash,
+)]
 #[serde(rename_all = "PascalCase")]
 #[strum(serialize_all = "PascalCase")]
 #[non_exhaustive]
@@ -282,7 +361,7 @@ pub enum Category {
     StrategyGame,
     /// Software to teach arts.
     Art,
-    ///
+    /// Software for construction and building.
     Construction,
     /// Musical software.
     Music,
@@ -410,6 +489,30 @@ pub enum Category {
     Unknown(String),
 }
 
+impl Category {
+    /// Whether the category is one of the
+    /// [main categories](https://specifications.freedesktop.org/menu-spec/latest/apa.html#main-category-registry),
+    /// the ones software centers usually display.
+    pub fn is_main(&self) -> bool {
+        matches!(
+            self,
+            Category::AudioVideo
+                | Category::Audio
+                | Category::Video
+                | Category::Development
+                | Category::Education
+                | Category::Game
+                | Category::Graphics
+                | Category::Network
+                | Category::Office
+                | Category::Science
+                | Category::Settings
+                | Category::System
+                | Category::Utility
+        )
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 #[serde(tag = "type", content = "$value")]
@@ -426,31 +529,28 @@ pub enum Checksum {
     Blake2s(String),
 }
 
-#[derive(Clone, Copy, Debug, AsRefStr, Serialize, Display, Deserialize, Default, PartialEq)]
-#[serde(rename_all = "kebab-case")]
+#[derive(Clone, Debug, AsRefStr, Default, PartialEq, Eq, Hash)]
 #[strum(serialize_all = "kebab-case")]
 #[non_exhaustive]
 /// Defines the various types of a `Component`.
+///
+/// The unknown types are kept as found in the metadata, the enum is not
+/// `Copy` for that reason.
 pub enum ComponentKind {
     /// A runtime.
     Runtime,
-    #[serde(alias = "console")]
     #[strum(serialize = "console")]
     /// A terminal application (CLI).
     ConsoleApplication,
-    #[serde(alias = "desktop")]
     #[strum(serialize = "desktop")]
     /// A desktop application.
     DesktopApplication,
-    #[serde(alias = "webapp")]
     #[strum(serialize = "web-application")]
     /// A web application.
     WebApplication,
-    #[serde(rename = "inputmethod")]
     #[strum(serialize = "inputmethod")]
     /// An input method.
     InputMethod,
-    #[serde(alias = "operating-system")]
     #[strum(serialize = "operating-system")]
     /// An operating system.
     OS,
@@ -473,6 +573,43 @@ pub enum ComponentKind {
     Driver,
     /// A codec.
     Codec,
+    /// A package repository configuration.
+    Repository,
+    #[doc(hidden)]
+    Unknown(String),
+}
+
+impl fmt::Display for ComponentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComponentKind::Unknown(kind) => f.pad(kind),
+            kind => f.pad(kind.as_ref()),
+        }
+    }
+}
+
+impl Serialize for ComponentKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(match self {
+            ComponentKind::ConsoleApplication => "console-application",
+            ComponentKind::DesktopApplication => "desktop-application",
+            ComponentKind::Unknown(kind) => kind,
+            kind => kind.as_ref(),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ComponentKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let kind = String::deserialize(deserializer)?;
+        ComponentKind::from_str(&kind).map_err(de::Error::custom)
+    }
 }
 
 impl FromStr for ComponentKind {
@@ -483,9 +620,9 @@ impl FromStr for ComponentKind {
             "runtime" => Ok(ComponentKind::Runtime),
             "console" | "console-application" => Ok(ComponentKind::ConsoleApplication),
             "desktop" | "desktop-application" => Ok(ComponentKind::DesktopApplication),
-            "webapp" => Ok(ComponentKind::WebApplication),
+            "webapp" | "web-application" => Ok(ComponentKind::WebApplication),
             "inputmethod" => Ok(ComponentKind::InputMethod),
-            "operating-system" => Ok(ComponentKind::OS),
+            "operating-system" | "os" => Ok(ComponentKind::OS),
             "theme" => Ok(ComponentKind::Theme),
             "firmware" => Ok(ComponentKind::Firmware),
             "addon" => Ok(ComponentKind::Addon),
@@ -494,8 +631,9 @@ impl FromStr for ComponentKind {
             "driver" => Ok(ComponentKind::Driver),
             "codec" => Ok(ComponentKind::Codec),
             "localization" => Ok(ComponentKind::Localization),
+            "repository" => Ok(ComponentKind::Repository),
             "" | "generic" => Ok(ComponentKind::default()),
-            _ => Err(ParseError::invalid_value(c, "type", "component")),
+            _ => Ok(ComponentKind::Unknown(c.to_string())),
         }
     }
 }
@@ -602,6 +740,80 @@ pub enum ContentAttribute {
     MoneyGambling(ContentState),
 }
 
+impl ContentAttribute {
+    /// The state of the attribute.
+    pub fn state(&self) -> ContentState {
+        match self {
+            ContentAttribute::ViolenceCartoon(state)
+            | ContentAttribute::ViolenceFantasy(state)
+            | ContentAttribute::ViolenceRealistic(state)
+            | ContentAttribute::ViolenceBloodshed(state)
+            | ContentAttribute::ViolenceSexual(state)
+            | ContentAttribute::ViolenceDesecration(state)
+            | ContentAttribute::ViolenceSlavery(state)
+            | ContentAttribute::ViolenceWorship(state)
+            | ContentAttribute::DrugsAlcohol(state)
+            | ContentAttribute::DrugsNarcotics(state)
+            | ContentAttribute::DrugsTobacco(state)
+            | ContentAttribute::SexNudity(state)
+            | ContentAttribute::SexThemes(state)
+            | ContentAttribute::SexHomosexuality(state)
+            | ContentAttribute::SexProstitution(state)
+            | ContentAttribute::SexAdultery(state)
+            | ContentAttribute::SexAppearance(state)
+            | ContentAttribute::LanguageProfanity(state)
+            | ContentAttribute::LanguageHumor(state)
+            | ContentAttribute::LanguageDiscrimination(state)
+            | ContentAttribute::SocialChat(state)
+            | ContentAttribute::SocialInfo(state)
+            | ContentAttribute::SocialAudio(state)
+            | ContentAttribute::SocialLocation(state)
+            | ContentAttribute::SocialContacts(state)
+            | ContentAttribute::MoneyAdvertising(state)
+            | ContentAttribute::MoneyPurchasing(state)
+            | ContentAttribute::MoneyGambling(state) => *state,
+        }
+    }
+
+    /// The minimum age suitable for the attribute, following the
+    /// [Common Sense Media](https://www.commonsensemedia.org/) mapping used
+    /// by libappstream.
+    pub fn minimum_age(&self) -> u32 {
+        // The ages for the none, mild, moderate and intense states.
+        let ages = match self {
+            ContentAttribute::ViolenceCartoon(_) => [0, 3, 4, 6],
+            ContentAttribute::ViolenceFantasy(_) => [0, 3, 7, 8],
+            ContentAttribute::ViolenceRealistic(_) => [0, 4, 9, 14],
+            ContentAttribute::ViolenceBloodshed(_) => [0, 9, 11, 18],
+            ContentAttribute::ViolenceSexual(_) => [0, 18, 18, 18],
+            ContentAttribute::ViolenceDesecration(_) => [0, 13, 15, 18],
+            ContentAttribute::ViolenceSlavery(_) => [0, 13, 15, 18],
+            ContentAttribute::ViolenceWorship(_) => [0, 13, 15, 18],
+            ContentAttribute::DrugsAlcohol(_) => [0, 11, 13, 16],
+            ContentAttribute::DrugsNarcotics(_) => [0, 12, 14, 17],
+            ContentAttribute::DrugsTobacco(_) => [0, 10, 13, 13],
+            ContentAttribute::SexNudity(_) => [0, 12, 14, 14],
+            ContentAttribute::SexThemes(_) => [0, 13, 14, 15],
+            ContentAttribute::SexHomosexuality(_) => [0, 13, 14, 15],
+            ContentAttribute::SexProstitution(_) => [0, 12, 14, 18],
+            ContentAttribute::SexAdultery(_) => [0, 8, 10, 18],
+            ContentAttribute::SexAppearance(_) => [0, 10, 10, 15],
+            ContentAttribute::LanguageProfanity(_) => [0, 8, 11, 14],
+            ContentAttribute::LanguageHumor(_) => [0, 3, 8, 14],
+            ContentAttribute::LanguageDiscrimination(_) => [0, 9, 10, 11],
+            ContentAttribute::SocialChat(_) => [0, 4, 10, 13],
+            ContentAttribute::SocialInfo(_) => [0, 0, 13, 13],
+            ContentAttribute::SocialAudio(_) => [0, 15, 15, 15],
+            ContentAttribute::SocialLocation(_) => [0, 13, 13, 13],
+            ContentAttribute::SocialContacts(_) => [0, 12, 12, 12],
+            ContentAttribute::MoneyAdvertising(_) => [0, 7, 8, 10],
+            ContentAttribute::MoneyPurchasing(_) => [0, 12, 14, 15],
+            ContentAttribute::MoneyGambling(_) => [0, 7, 10, 18],
+        };
+        ages[self.state() as usize]
+    }
+}
+
 #[derive(Clone, Copy, Eq, PartialEq, Deserialize, Serialize, Debug, Default)]
 #[non_exhaustive]
 /// Defines the version of the OARS specification.
@@ -639,9 +851,46 @@ impl PartialOrd for ContentRatingVersion {
 #[derive(Clone, Copy, Debug, AsRefStr, Display, EnumString, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
-/// Defines the state of a `ContentAttribute`
+#[non_exhaustive]
+/// Defines the type of a branding color.
+/// See [\<branding\/\>](https://www.freedesktop.org/software/appstream/docs/chap-Metadata.html#tag-branding).
+pub enum ColorKind {
+    /// The primary brand color.
+    Primary,
+}
+
+#[derive(Clone, Copy, Debug, AsRefStr, Display, EnumString, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+/// Defines the color scheme a branding color is meant for.
+pub enum ColorScheme {
+    /// A light color scheme.
+    Light,
+    /// A dark color scheme.
+    Dark,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    AsRefStr,
+    Display,
+    EnumString,
+    Deserialize,
+    Serialize,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Default,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+/// Defines the state of a `ContentAttribute`, ordered by intensity.
 pub enum ContentState {
     /// No state is set.
+    #[default]
     None,
     /// Mild state.
     Mild,
@@ -651,13 +900,9 @@ pub enum ContentState {
     Intense,
 }
 
-impl Default for ContentState {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
-#[derive(Clone, Copy, Debug, AsRefStr, EnumString, Display, Deserialize, Serialize, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, AsRefStr, EnumString, Display, Deserialize, Serialize, PartialEq, Eq, Hash,
+)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
 /// Defines the firmware type.
@@ -709,6 +954,86 @@ pub enum Icon {
     },
 }
 
+impl Icon {
+    /// Create a new `Icon` from an XML element in strict mode.
+    ///
+    /// Unlike `Icon::try_from`, which ignores the dimensions it can't parse,
+    /// like `width="128px"`, this fails with `ParseError::InvalidValue`.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - The `<icon>` element.
+    pub fn try_from_strict(element: &xmltree::Element) -> Result<Self, ParseError> {
+        for attribute in ["width", "height", "scale"] {
+            if let Some(value) = element.attributes.get(attribute) {
+                if value.parse::<u32>().is_err() {
+                    return Err(ParseError::invalid_value(value, attribute, "icon"));
+                }
+            }
+        }
+        Icon::try_from(element)
+    }
+
+    /// The `(width, height)` of the icon if both are known.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::enums::Icon;
+    ///
+    /// let icons = vec![
+    ///     Icon::Stock("web-browser".into()),
+    ///     Icon::Cached {
+    ///         path: "firefox.png".into(),
+    ///         width: Some(64),
+    ///         height: Some(64),
+    ///         scale: None,
+    ///     },
+    /// ];
+    /// for icon in &icons {
+    ///     println!("{:?}: {:?}", icon.location(), icon.dimensions());
+    /// }
+    /// assert_eq!(icons[1].dimensions(), Some((64, 64)));
+    /// ```
+    pub fn dimensions(&self) -> Option<(u32, u32)> {
+        match self {
+            Icon::Stock(_) => None,
+            Icon::Cached { width, height, .. }
+            | Icon::Remote { width, height, .. }
+            | Icon::Local { width, height, .. } => width.zip(*height),
+        }
+    }
+
+    /// The scale factor of the icon, if any.
+    pub fn scale(&self) -> Option<u32> {
+        match self {
+            Icon::Stock(_) => None,
+            Icon::Cached { scale, .. } | Icon::Remote { scale, .. } | Icon::Local { scale, .. } => {
+                *scale
+            }
+        }
+    }
+
+    /// Where the icon can be loaded from.
+    pub fn location(&self) -> IconLocation<'_> {
+        match self {
+            Icon::Stock(name) => IconLocation::Stock(name),
+            Icon::Cached { path, .. } | Icon::Local { path, .. } => IconLocation::Path(path),
+            Icon::Remote { url, .. } => IconLocation::Url(url),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Where an `Icon` can be loaded from.
+pub enum IconLocation<'a> {
+    /// The name of a stock icon.
+    Stock(&'a str),
+    /// The path of a cached or local icon.
+    Path(&'a Path),
+    /// The URL of a remote icon.
+    Url(&'a Url),
+}
+
 impl<'de> Deserialize<'de> for Icon {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -866,23 +1191,20 @@ impl Serialize for Icon {
     }
 }
 
-#[derive(Clone, Copy, Debug, Display, AsRefStr, Serialize, Deserialize, PartialEq, EnumString)]
+#[derive(
+    Clone, Copy, Debug, Display, AsRefStr, Serialize, Deserialize, PartialEq, EnumString, Default,
+)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
 /// The type of an image.
 pub enum ImageKind {
     /// The source image.
+    #[default]
     Source,
     /// A thumbnail image.
     Thumbnail,
 }
 
-impl Default for ImageKind {
-    fn default() -> Self {
-        Self::Source
-    }
-}
-
 #[derive(Clone, Debug, Deserialize, AsRefStr, Display, Serialize, PartialEq, EnumString)]
 #[strum(serialize_all = "PascalCase")]
 #[non_exhaustive]
@@ -905,6 +1227,8 @@ pub enum Kudo {
     SearchProvider,
     /// Provides user documentation/help.
     UserDocs,
+    /// The application is published on Flathub by its upstream developers.
+    FlatpakVerified,
     #[strum(default)]
     #[doc(hidden)]
     Unknown(String),
@@ -925,6 +1249,7 @@ pub enum Launchable {
     Service(String),
     /// The application is a website viewed through a browser.
     Url(Url),
+    #[serde(alias = "cockpit_manifest")]
     /// The software can be launched from the menus of the [Cockpit](http://cockpit-project.org/) admin interface.
     CockpitManifest(String),
     #[doc(hidden)]
@@ -973,7 +1298,7 @@ pub enum ProjectUrl {
     Donation(Url),
     /// To submit or modify translations.
     Translate(Url),
-    /// Upstream homepage.
+    /// Upstream homepage. Also used for `<url>` tags without a `type`.
     Homepage(Url),
     /// Bug tracking system, to report new bugs.
     BugTracker(Url),
@@ -1031,7 +1356,59 @@ impl Serialize for ProjectUrl {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+impl ProjectUrl {
+    /// The kind of the URL.
+    pub fn kind(&self) -> ProjectUrlKind {
+        match self {
+            ProjectUrl::Donation(_) => ProjectUrlKind::Donation,
+            ProjectUrl::Translate(_) => ProjectUrlKind::Translate,
+            ProjectUrl::Homepage(_) => ProjectUrlKind::Homepage,
+            ProjectUrl::BugTracker(_) => ProjectUrlKind::BugTracker,
+            ProjectUrl::Help(_) => ProjectUrlKind::Help,
+            ProjectUrl::Faq(_) => ProjectUrlKind::Faq,
+            ProjectUrl::Contact(_) => ProjectUrlKind::Contact,
+            ProjectUrl::Unknown(_) => ProjectUrlKind::Unknown,
+        }
+    }
+
+    /// The URL itself.
+    pub fn url(&self) -> &Url {
+        match self {
+            ProjectUrl::Donation(url)
+            | ProjectUrl::Translate(url)
+            | ProjectUrl::Homepage(url)
+            | ProjectUrl::BugTracker(url)
+            | ProjectUrl::Help(url)
+            | ProjectUrl::Faq(url)
+            | ProjectUrl::Contact(url)
+            | ProjectUrl::Unknown(url) => url,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// The kind of a `ProjectUrl`, regardless of its URL.
+pub enum ProjectUrlKind {
+    /// See `ProjectUrl::Donation`.
+    Donation,
+    /// See `ProjectUrl::Translate`.
+    Translate,
+    /// See `ProjectUrl::Homepage`.
+    Homepage,
+    /// See `ProjectUrl::BugTracker`.
+    BugTracker,
+    /// See `ProjectUrl::Help`.
+    Help,
+    /// See `ProjectUrl::Faq`.
+    Faq,
+    /// See `ProjectUrl::Contact`.
+    Contact,
+    #[doc(hidden)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 /// Describes the public interfaces the component provides.
 /// See [\<provide\/\>](https://www.freedesktop.org/software/appstream/docs/chap-Metadata.html#tag-provides).
@@ -1039,8 +1416,8 @@ impl Serialize for ProjectUrl {
 pub enum Provide {
     /// Media type (also known as MIME type)
     MediaType(String),
-    /// Shared library.
-    Library(PathBuf),
+    /// Shared library, by its soname, e.g. `libfoo.so.2`.
+    Library(String),
     /// Name of a binary installed in `$PATH`.
     Binary(String),
     /// Full name of a font.
@@ -1065,6 +1442,107 @@ pub enum Provide {
     Id(AppId),
     /// Required only for Codec components.
     Codec(String),
+    /// A provided item of a kind that is not known by the specifications.
+    Unknown {
+        /// The name of the tag.
+        tag: String,
+        /// Value
+        value: String,
+    },
+}
+
+impl Provide {
+    /// Create a new `Provide` from its kind, the name of its tag, and its
+    /// value.
+    ///
+    /// The firmware type is expected as a prefix of the value, e.g.
+    /// `runtime:cea3f2c1-8c48-5d41-8ea2-4e8d2e3d5c10`. An unknown kind is kept
+    /// as a `Provide::Unknown`.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The provided item kind, like `binary` or `library`.
+    /// * `value` - The provided item value.
+    pub fn from_kind_value(kind: &str, value: &str) -> Result<Self, ParseError> {
+        match kind {
+            "mediatype" => Ok(Provide::MediaType(value.to_string())),
+            "library" => Ok(Provide::Library(value.to_string())),
+            "binary" => Ok(Provide::Binary(value.to_string())),
+            "font" => Ok(Provide::Font(value.to_string())),
+            "modalias" => Ok(Provide::Modalias(value.to_string())),
+            "python2" => Ok(Provide::Python2(value.to_string())),
+            "python3" => Ok(Provide::Python3(value.to_string())),
+            "dbus" => Ok(Provide::DBus(value.to_string())),
+            "id" => {
+                let id = value.trim();
+                if id.is_empty() {
+                    Err(ParseError::missing_value("id"))
+                } else if id.contains(char::is_whitespace) {
+                    Err(ParseError::invalid_value(id, "$value", "id"))
+                } else {
+                    Ok(Provide::Id(id.into()))
+                }
+            }
+            "codec" => Ok(Provide::Codec(value.to_string())),
+            "firmware" => {
+                let (firmware_kind, item) = value
+                    .split_once(':')
+                    .ok_or_else(|| ParseError::missing_attribute("type", "firmware"))?;
+                let firmware_kind = FirmwareKind::from_str(firmware_kind)
+                    .map_err(|_| ParseError::invalid_value(firmware_kind, "type", "firmware"))?;
+                Ok(Provide::Firmware {
+                    kind: firmware_kind,
+                    item: item.to_string(),
+                })
+            }
+            // Keep the data of the unknown kinds around.
+            tag => Ok(Provide::Unknown {
+                tag: tag.to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+
+    /// The name of the provided binary, the last segment of its path, e.g.
+    /// `foobar` for `usr/bin/foobar`. `None` if it's not a `Provide::Binary`.
+    pub fn binary_name(&self) -> Option<&str> {
+        match self {
+            Provide::Binary(binary) => binary.rsplit('/').next(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Provide {
+    /// Formats the provided item as `kind:value`, e.g. `binary:foobar`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Provide::MediaType(value) => write!(f, "mediatype:{}", value),
+            Provide::Library(soname) => write!(f, "library:{}", soname),
+            Provide::Binary(value) => write!(f, "binary:{}", value),
+            Provide::Font(value) => write!(f, "font:{}", value),
+            Provide::Modalias(value) => write!(f, "modalias:{}", value),
+            Provide::Firmware { kind, item } => write!(f, "firmware:{}:{}", kind, item),
+            Provide::Python2(value) => write!(f, "python2:{}", value),
+            Provide::Python3(value) => write!(f, "python3:{}", value),
+            Provide::DBus(value) => write!(f, "dbus:{}", value),
+            Provide::Id(id) => write!(f, "id:{}", id),
+            Provide::Codec(value) => write!(f, "codec:{}", value),
+            Provide::Unknown { tag, value } => write!(f, "{}:{}", tag, value),
+        }
+    }
+}
+
+impl FromStr for Provide {
+    type Err = ParseError;
+
+    /// Parses a provided item from its `kind:value` form.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, value) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::invalid_value(s, "$value", "provide"))?;
+        Provide::from_kind_value(kind, value)
+    }
 }
 
 #[derive(Clone, Copy, Debug, Display, EnumString, AsRefStr, Serialize, Deserialize, PartialEq)]
@@ -1099,6 +1577,40 @@ pub enum ReleaseUrgency {
     Critical,
 }
 
+#[derive(
+    Clone, Copy, Debug, AsRefStr, EnumString, Display, Serialize, Deserialize, PartialEq, Default,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+/// Defines how a catalog component merges with the component sharing its id,
+/// set through the `merge` attribute of `<component>`.
+pub enum MergeKind {
+    /// The component is not meant to be merged.
+    #[default]
+    None,
+    /// The data of the component replaces the one of the existing component.
+    Replace,
+    /// The data of the component is appended to the existing component.
+    Append,
+    /// The existing component is removed.
+    RemoveComponent,
+}
+
+#[derive(
+    Clone, Copy, Debug, AsRefStr, EnumString, Display, Serialize, Deserialize, PartialEq, Default,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+/// Defines the order in which the releases are listed, set through the
+/// `order` attribute of `<releases>`.
+pub enum ReleaseOrder {
+    /// The newest release comes first.
+    #[default]
+    Inverse,
+    /// The oldest release comes first.
+    Ascending,
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", content = "$value", rename_all = "kebab-case")]
 #[non_exhaustive]
@@ -1123,3 +1635,311 @@ pub enum Translation {
     #[doc(hidden)]
     Unknown,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryFrom, error::Error};
+
+    use super::*;
+
+    #[test]
+    fn component_kind_round_trip() -> Result<(), Box<dyn Error>> {
+        let kinds = vec![
+            ComponentKind::Runtime,
+            ComponentKind::ConsoleApplication,
+            ComponentKind::DesktopApplication,
+            ComponentKind::WebApplication,
+            ComponentKind::InputMethod,
+            ComponentKind::OS,
+            ComponentKind::Theme,
+            ComponentKind::Firmware,
+            ComponentKind::Addon,
+            ComponentKind::Font,
+            ComponentKind::Generic,
+            ComponentKind::IconTheme,
+            ComponentKind::Localization,
+            ComponentKind::Driver,
+            ComponentKind::Codec,
+            ComponentKind::Repository,
+        ];
+        for kind in kinds {
+            assert_eq!(ComponentKind::from_str(kind.as_ref())?, kind);
+        }
+
+        assert_eq!(
+            ComponentKind::from_str("console-application")?,
+            ComponentKind::ConsoleApplication
+        );
+        assert_eq!(
+            ComponentKind::from_str("repository")?,
+            ComponentKind::Repository
+        );
+
+        let unknown = ComponentKind::from_str("mimetype-handler")?;
+        assert_eq!(unknown, ComponentKind::Unknown("mimetype-handler".into()));
+        assert_eq!(unknown.to_string(), "mimetype-handler");
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "test_json")]
+    fn component_kind_json_round_trip() -> Result<(), Box<dyn Error>> {
+        let kinds = vec![
+            ComponentKind::DesktopApplication,
+            ComponentKind::OS,
+            ComponentKind::IconTheme,
+            ComponentKind::Unknown("mimetype-handler".into()),
+        ];
+
+        for kind in kinds {
+            let json = serde_json::to_string(&kind)?;
+            assert_eq!(serde_json::from_str::<ComponentKind>(&json)?, kind);
+        }
+        assert_eq!(
+            serde_json::to_string(&ComponentKind::Unknown("mimetype-handler".into()))?,
+            "\"mimetype-handler\""
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn content_state_ordering() {
+        assert!(ContentState::Intense > ContentState::Mild);
+        assert!(ContentState::None < ContentState::Mild);
+        assert!(ContentState::Moderate < ContentState::Intense);
+        assert_eq!(
+            [
+                ContentState::Mild,
+                ContentState::Intense,
+                ContentState::None
+            ]
+            .into_iter()
+            .max(),
+            Some(ContentState::Intense)
+        );
+    }
+
+    #[test]
+    fn main_category() {
+        assert!(Category::AudioVideo.is_main());
+        assert!(Category::Utility.is_main());
+        assert!(!Category::BlocksGame.is_main());
+        assert!(!Category::Unknown("Foo".into()).is_main());
+    }
+
+    #[test]
+    fn provide_library_soname() -> Result<(), Box<dyn Error>> {
+        let xml = r"<library>libfoobar.so.2</library>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let provide = Provide::try_from(&element)?;
+        assert_eq!(provide, Provide::Library("libfoobar.so.2".into()));
+        assert_eq!(
+            provide,
+            Provide::from_kind_value("library", "libfoobar.so.2")?
+        );
+        assert_ne!(provide, Provide::Library("libfoobar.so.1".into()));
+        Ok(())
+    }
+
+    #[test]
+    fn provide_display_round_trip() -> Result<(), Box<dyn Error>> {
+        let provides = vec![
+            (
+                Provide::MediaType("text/html".into()),
+                "mediatype:text/html",
+            ),
+            (
+                Provide::Library("libfoo.so.2".into()),
+                "library:libfoo.so.2",
+            ),
+            (Provide::Binary("foobar".into()), "binary:foobar"),
+            (Provide::Font("Foo Sans".into()), "font:Foo Sans"),
+            (
+                Provide::Modalias("usb:v1130p0202d*".into()),
+                "modalias:usb:v1130p0202d*",
+            ),
+            (
+                Provide::Firmware {
+                    kind: FirmwareKind::Runtime,
+                    item: "84f40464-9272-4ef7-9399-cd95f12da696".into(),
+                },
+                "firmware:runtime:84f40464-9272-4ef7-9399-cd95f12da696",
+            ),
+            (Provide::Python2("foo".into()), "python2:foo"),
+            (Provide::Python3("foo".into()), "python3:foo"),
+            (
+                Provide::DBus("org.example.Foo".into()),
+                "dbus:org.example.Foo",
+            ),
+            (Provide::Id("org.example.Foo".into()), "id:org.example.Foo"),
+            (Provide::Codec("mpeg4".into()), "codec:mpeg4"),
+            (
+                Provide::Unknown {
+                    tag: "somethingnew".into(),
+                    value: "foo".into(),
+                },
+                "somethingnew:foo",
+            ),
+        ];
+
+        for (provide, text) in provides {
+            assert_eq!(provide.to_string(), text);
+            assert_eq!(Provide::from_str(text)?, provide);
+        }
+        assert!(Provide::from_str("foobar").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn typeless_project_url() -> Result<(), Box<dyn Error>> {
+        let xml = r"<url>https://example.com/foobar</url>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let url = ProjectUrl::try_from(&element)?;
+
+        assert_eq!(
+            url,
+            ProjectUrl::Homepage(Url::parse("https://example.com/foobar")?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn icon_invalid_dimensions() -> Result<(), Box<dyn Error>> {
+        let xml = r"<icon type='cached' width='128px' height='128'>foobar.png</icon>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+
+        let icon = Icon::try_from(&element)?;
+        assert_eq!(
+            icon,
+            Icon::Cached {
+                path: "foobar.png".into(),
+                width: None,
+                height: Some(128),
+                scale: None,
+            }
+        );
+
+        let error = Icon::try_from_strict(&element).unwrap_err();
+        assert!(matches!(
+            error,
+            ParseError::InvalidValue(value, attribute, _) if value == "128px" && attribute == "width"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn stock_icon() -> Result<(), Box<dyn Error>> {
+        let xml = r"<icon type='stock'>
+            web-browser
+        </icon>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let icon = Icon::try_from(&element)?;
+
+        assert_eq!(icon, Icon::Stock("web-browser".into()));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "test_json")]
+    fn launchable_json_round_trip() -> Result<(), Box<dyn Error>> {
+        let launchables = vec![
+            Launchable::DesktopId("org.gnome.design.Contrast.desktop".into()),
+            Launchable::Service("org.freedesktop.fwupd.service".into()),
+            Launchable::Url(Url::parse("https://example.com/app")?),
+            Launchable::CockpitManifest("foobar".into()),
+            Launchable::Unknown("foobar".into()),
+        ];
+
+        for launchable in launchables {
+            let json = serde_json::to_string(&launchable)?;
+            assert_eq!(serde_json::from_str::<Launchable>(&json)?, launchable);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "test_json")]
+    fn bundle_xml_json_round_trip() -> Result<(), Box<dyn Error>> {
+        let xml = r#"<bundle type="flatpak" runtime="org.gnome.Platform/x86_64/3.36" sdk="org.gnome.Sdk/x86_64/3.36">app/org.gnome.design.Contrast/x86_64/stable</bundle>"#;
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let bundle = Bundle::try_from(&element)?;
+        assert_eq!(
+            bundle,
+            Bundle::Flatpak {
+                runtime: Some("org.gnome.Platform/x86_64/3.36".into()),
+                sdk: Some("org.gnome.Sdk/x86_64/3.36".into()),
+                reference: "app/org.gnome.design.Contrast/x86_64/stable".into(),
+            }
+        );
+
+        let json = serde_json::to_value(&bundle)?;
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "flatpak",
+                "reference": "app/org.gnome.design.Contrast/x86_64/stable",
+                "sdk": "org.gnome.Sdk/x86_64/3.36",
+                "runtime": "org.gnome.Platform/x86_64/3.36",
+            })
+        );
+        assert_eq!(serde_json::from_value::<Bundle>(json)?, bundle);
+
+        let bundles = vec![
+            Bundle::Flatpak {
+                runtime: None,
+                sdk: None,
+                reference: "app/org.gnome.design.Contrast/x86_64/stable".into(),
+            },
+            Bundle::Limba("foobar-1.0.2".into()),
+            Bundle::AppImage("https://example.com/foobar.AppImage".into()),
+            Bundle::Snap {
+                id: "foobar".into(),
+                channel: None,
+            },
+            Bundle::Snap {
+                id: "foobar".into(),
+                channel: Some("edge".into()),
+            },
+            Bundle::Tarball("foobar-1.0.2.tar.xz".into()),
+        ];
+        for bundle in bundles {
+            let json = serde_json::to_string(&bundle)?;
+            assert_eq!(serde_json::from_str::<Bundle>(&json)?, bundle);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn snap_bundle_channel() -> Result<(), Box<dyn Error>> {
+        let xml = r#"<bundle type="snap" channel="stable">foobar</bundle>"#;
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        assert_eq!(
+            Bundle::try_from(&element)?,
+            Bundle::Snap {
+                id: "foobar".into(),
+                channel: Some("stable".into()),
+            }
+        );
+
+        let xml = r#"<bundle type="snap">foobar</bundle>"#;
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        assert_eq!(
+            Bundle::try_from(&element)?,
+            Bundle::Snap {
+                id: "foobar".into(),
+                channel: None,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn empty_stock_icon() -> Result<(), Box<dyn Error>> {
+        for xml in [r"<icon type='stock'/>", r"<icon type='stock'>   </icon>"] {
+            let element = xmltree::Element::parse(xml.as_bytes())?;
+            let error = Icon::try_from(&element).unwrap_err();
+            assert!(matches!(error, ParseError::MissingValue(tag) if tag == "icon"));
+        }
+        Ok(())
+    }
+}
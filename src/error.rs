@@ -1,11 +1,17 @@
 use std::fmt::{Debug, Display, Formatter};
 
 use thiserror::Error;
+use xml::common::Position;
 
 use crate::collection::Collection;
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 /// Error happened during the parsing process.
+///
+/// Some variants depend on the enabled features, like
+/// `ParseError::ChronoParseError` with `chrono` or `ParseError::JsonError`
+/// with `json`, so the enum is not exhaustive.
 pub enum ParseError {
     #[error("XML parser error: {0}")]
     /// Xml error.
@@ -15,10 +21,16 @@ pub enum ParseError {
     /// url failed to parse a URL.
     UrlParseError(#[from] url::ParseError),
 
+    #[cfg(feature = "chrono")]
     #[error("chrono parser error: {0}")]
-    /// url failed to parse a URL.
+    /// chrono failed to parse a date.
     ChronoParseError(#[from] chrono::ParseError),
 
+    #[cfg(feature = "json")]
+    #[error("JSON error: {0}")]
+    /// serde_json failed to read or write JSON.
+    JsonError(#[from] serde_json::Error),
+
     #[error("Input/output error: {0} ")]
     /// IO.
     IOError(#[from] std::io::Error),
@@ -46,6 +58,11 @@ pub enum ParseError {
     #[error("Error parsing {0}: {1}")]
     /// A parsing error requiring a reason.
     Other(String, String),
+
+    #[error("Unsupported feature: {0}")]
+    /// A feature of the specifications that is recognized but not supported
+    /// yet. Only returned when parsing in strict mode.
+    Unsupported(String),
 }
 
 impl ParseError {
@@ -78,6 +95,25 @@ impl ParseError {
     pub fn other(tag: &str, reason: &str) -> Self {
         ParseError::Other(tag.to_string(), reason.to_string())
     }
+
+    /// Creates an unsupported feature error.
+    pub fn unsupported(feature: &str) -> Self {
+        ParseError::Unsupported(feature.to_string())
+    }
+
+    /// The line and column, both starting at 1, where the XML parser failed.
+    ///
+    /// Only known for a malformed XML document, the errors raised while
+    /// reading the parsed elements don't have a position.
+    pub fn position(&self) -> Option<(u64, u64)> {
+        match self {
+            ParseError::XmlParserError(xmltree::ParseError::MalformedXml(error)) => {
+                let position = error.position();
+                Some((position.row + 1, position.column + 1))
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(Error)]
@@ -95,6 +131,11 @@ impl ContextParseError {
             context: Some(context),
         }
     }
+
+    /// The name of the element the error occurred in, if known.
+    pub fn tag(&self) -> Option<&str> {
+        self.context.as_ref().map(|context| context.name.as_str())
+    }
 }
 
 impl Debug for ContextParseError {
@@ -192,3 +233,42 @@ fn display_context(context: &xmltree::Element, f: &Formatter<'_>, debug: bool) -
         format!(" | {}", snippet.replace('\n', "\n | "))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use super::ParseError;
+    use crate::Collection;
+
+    #[test]
+    fn xml_error_position() {
+        let xml = "<component>\n  <id>com.example.foobar</id>\n  <name>Foo Bar</nam>\n</component>";
+        let error = ParseError::from(xmltree::Element::parse(xml.as_bytes()).unwrap_err());
+        assert_eq!(error.position().map(|(line, _)| line), Some(3));
+        assert!(error.to_string().contains("3:"));
+
+        assert_eq!(ParseError::missing_tag("id").position(), None);
+    }
+
+    #[test]
+    fn context_error_tag() {
+        let xml = r"
+            <components version='0.14'>
+                <component>
+                    <name>Foo Bar</name>
+                </component>
+            </components>";
+        let element = xmltree::Element::parse(xml.as_bytes()).unwrap();
+        let error = Collection::try_from(&element)
+            .map(|_| ())
+            .unwrap_err()
+            .errors
+            .remove(0);
+        assert_eq!(error.tag(), Some("component"));
+
+        let message = error.to_string();
+        assert!(message.starts_with("A required tag is missing: id"));
+        assert!(message.contains("<component>"));
+    }
+}
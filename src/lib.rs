@@ -26,7 +26,6 @@
 //!     enums::{ProjectUrl, Provide},
 //!     Component, ParseError, TranslatableString,
 //! };
-//! use chrono::{TimeZone, Utc};
 //! use url::Url;
 //!
 //! fn main() -> Result<(), ParseError> {
@@ -44,7 +43,7 @@
 //!                           <binary>foobar</binary>
 //!                         </provides>
 //!                         <releases>
-//!                           <release version='1.2' date='2015-02-16'/>
+//!                           <release version='1.2'/>
 //!                         </releases>
 //!                         <developer_name>FooBar Team</developer_name>
 //!                     </component>";
@@ -61,11 +60,7 @@
 //!         .provide(Provide::Library("libfoobar.so.2".into()))
 //!         .provide(Provide::Font("foo.ttf".into()))
 //!         .provide(Provide::Binary("foobar".into()))
-//!         .release(
-//!             ReleaseBuilder::new("1.2")
-//!                 .date(Utc.ymd(2015, 2, 16).and_hms_milli(0, 0, 0, 0))
-//!                 .build(),
-//!         )
+//!         .release(ReleaseBuilder::new("1.2").build())
 //!         .build();
 //!
 //!     assert_eq!(c1, c2);
@@ -102,9 +97,23 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! # Features
+//!
+//! * `gzip` - Parse gzipped collections and components.
+//! * `json` - Read and write collections and components as JSON files.
+//! * `zstd` - Parse zstd compressed collections with
+//!   `Collection::from_compressed_path`.
+//! * `tokio` - Load collections from an asynchronous reader.
+//! * `chrono` (default) - Parse the release dates into [`chrono`](https://docs.rs/chrono/) types.
+//!
+//! Without the `chrono` feature, the release dates are kept as the raw strings
+//! found in the metadata. Parsing never fails on a date format, but the dates
+//! are neither validated nor comparable.
 #![deny(missing_docs)]
 
 mod app_id;
+mod branding;
 /// Various helpers to build any appstream type.
 pub mod builders;
 mod collection;
@@ -115,22 +124,29 @@ pub mod enums;
 mod error;
 mod language;
 mod license;
+/// Re-exports of the commonly used types.
+pub mod prelude;
 mod release;
 mod requirements;
 mod screenshot;
 mod translatable_string;
+mod validation;
 mod xml;
 
 pub use app_id::AppId;
+pub use branding::{Branding, BrandingColor};
 pub use collection::Collection;
-pub use component::Component;
+pub use component::{Component, StripOptions};
 pub use content_rating::ContentRating;
 pub use error::{ContextParseError, ParseError};
 pub use language::Language;
 pub use license::License;
-pub use release::{Artifact, Release};
-pub use requirements::{Control, DisplayLength, DisplayLengthValue, Requirement};
+pub use release::{Artifact, DateTime, Release};
+pub use requirements::{Control, DisplayLength, DisplayLengthValue, Rel, Requirement, Side};
 pub use screenshot::{Image, Screenshot, Video};
-pub use translatable_string::{MarkupTranslatableString, TranslatableList, TranslatableString};
+pub use translatable_string::{
+    normalize_locale, MarkupTranslatableString, TranslatableList, TranslatableString,
+};
 pub use url;
+pub use validation::ValidationWarning;
 pub use xmltree;
@@ -23,3 +23,79 @@ impl fmt::Display for License {
         f.write_str(&self.0)
     }
 }
+
+/// Legacy or deprecated license spellings and their current SPDX identifier.
+const LEGACY_LICENSES: &[(&str, &str)] = &[
+    ("GPLv2", "GPL-2.0-only"),
+    ("GPL-2.0", "GPL-2.0-only"),
+    ("GPLv2+", "GPL-2.0-or-later"),
+    ("GPL-2.0+", "GPL-2.0-or-later"),
+    ("GPLv3", "GPL-3.0-only"),
+    ("GPL-3.0", "GPL-3.0-only"),
+    ("GPLv3+", "GPL-3.0-or-later"),
+    ("GPL-3.0+", "GPL-3.0-or-later"),
+    ("LGPLv2.1", "LGPL-2.1-only"),
+    ("LGPL-2.1", "LGPL-2.1-only"),
+    ("LGPLv2.1+", "LGPL-2.1-or-later"),
+    ("LGPL-2.1+", "LGPL-2.1-or-later"),
+    ("LGPLv3", "LGPL-3.0-only"),
+    ("LGPL-3.0", "LGPL-3.0-only"),
+    ("LGPLv3+", "LGPL-3.0-or-later"),
+    ("LGPL-3.0+", "LGPL-3.0-or-later"),
+    ("AGPLv3", "AGPL-3.0-only"),
+    ("AGPL-3.0", "AGPL-3.0-only"),
+    ("AGPLv3+", "AGPL-3.0-or-later"),
+    ("AGPL-3.0+", "AGPL-3.0-or-later"),
+    ("CC0", "CC0-1.0"),
+    ("Apache-2", "Apache-2.0"),
+    ("MPL-2", "MPL-2.0"),
+];
+
+impl License {
+    /// Returns the license with the common legacy spellings, like `GPLv2+`
+    /// or `GPL-2.0+`, replaced by their current SPDX identifier. Each
+    /// identifier of a license expression is normalized and the unknown
+    /// ones are kept untouched.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::License;
+    ///
+    /// let license = License::from("GPL-3.0+ AND CC0");
+    /// assert_eq!(license.normalized(), License::from("GPL-3.0-or-later AND CC0-1.0"));
+    /// ```
+    pub fn normalized(&self) -> License {
+        let expression = self
+            .0
+            .split(' ')
+            .map(|token| {
+                let id = token.trim_matches(|c| c == '(' || c == ')');
+                match LEGACY_LICENSES.iter().find(|(legacy, _)| *legacy == id) {
+                    Some((_, spdx)) => token.replace(id, spdx),
+                    None => token.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        License(expression)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::License;
+
+    #[test]
+    fn normalized_license() {
+        assert_eq!(
+            License::from("GPL-2.0+").normalized(),
+            License::from("GPL-2.0-or-later")
+        );
+        assert_eq!(License::from("CC0").normalized(), License::from("CC0-1.0"));
+        assert_eq!(
+            License::from("(GPLv2+ OR MIT) AND LicenseRef-proprietary").normalized(),
+            License::from("(GPL-2.0-or-later OR MIT) AND LicenseRef-proprietary")
+        );
+        assert_eq!(License::from("MIT").normalized(), License::from("MIT"));
+    }
+}
@@ -0,0 +1,27 @@
+//! A single glob import covers the typical needs.
+//!
+//! # Example
+//! ```
+//! use appstream::prelude::*;
+//!
+//! let component = ComponentBuilder::default()
+//!     .id("com.example.foobar".into())
+//!     .name(TranslatableString::with_default("Foo Bar"))
+//!     .kind(ComponentKind::DesktopApplication)
+//!     .category(Category::Utility)
+//!     .provide(Provide::Binary("foobar".into()))
+//!     .build();
+//!
+//! let collection = Collection::from(component);
+//! assert_eq!(collection.components[0].kind, ComponentKind::DesktopApplication);
+//! ```
+
+pub use crate::{
+    builders::{
+        ArtifactBuilder, CollectionBuilder, ComponentBuilder, DisplayLengthBuilder, ImageBuilder,
+        LanguageBuilder, ReleaseBuilder, ScreenshotBuilder, VideoBuilder,
+    },
+    enums::{Category, ComponentKind, Icon, ProjectUrl, Provide},
+    AppId, Collection, Component, MarkupTranslatableString, ParseError, TranslatableList,
+    TranslatableString,
+};
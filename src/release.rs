@@ -1,4 +1,5 @@
-use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+
 use serde::{Deserialize, Serialize};
 use url::Url;
 
@@ -7,17 +8,29 @@ use super::{
     MarkupTranslatableString,
 };
 
+#[cfg(feature = "chrono")]
+/// A date attached to a release.
+pub type DateTime = chrono::DateTime<chrono::Utc>;
+
+#[cfg(not(feature = "chrono"))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(transparent)]
+/// A date attached to a release, kept as found in the metadata as the
+/// `chrono` feature is disabled.
+pub struct DateTime(pub String);
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 /// Represents the metainformation that defines a Release.
 /// See [\<releases\/\>](https://www.freedesktop.org/software/appstream/docs/chap-Metadata.html#tag-releases).
 pub struct Release {
     #[serde(default, alias = "timestamp", skip_serializing_if = "Option::is_none")]
-    /// The release date.
-    pub date: Option<DateTime<Utc>>,
+    /// The release date. When both the `timestamp` and `date` attributes are
+    /// set, the `timestamp` one is used.
+    pub date: Option<DateTime>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     /// The end-of-life date of the release.
-    pub date_eol: Option<DateTime<Utc>>,
+    pub date_eol: Option<DateTime>,
     /// The release version
     pub version: String,
 
@@ -33,7 +46,7 @@ pub struct Release {
     /// Defines the downloaded & installed sizes of the release.
     pub sizes: Vec<Size>,
 
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_default_urgency")]
     /// The urgency to install this release.
     pub urgency: ReleaseUrgency,
 
@@ -42,10 +55,108 @@ pub struct Release {
     pub artifacts: Vec<Artifact>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    /// A web page with the release changelog.
+    /// A web page with the release changelog, the `<url type="details">` of
+    /// the release.
     pub url: Option<Url>,
 }
 
+impl Release {
+    /// Whether installing the release is critical.
+    pub fn is_critical(&self) -> bool {
+        self.urgency == ReleaseUrgency::Critical
+    }
+
+    #[cfg(feature = "chrono")]
+    /// Whether the release reached its end-of-life date.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The date to compare the end-of-life date with.
+    pub fn is_eol(&self, now: DateTime) -> bool {
+        self.date_eol.is_some_and(|eol| eol < now)
+    }
+
+    #[cfg(feature = "chrono")]
+    /// The number of days elapsed since the release date, `None` if the
+    /// release has no date.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The date to compute the age at.
+    pub fn age_days(&self, now: DateTime) -> Option<i64> {
+        self.date.map(|date| (now - date).num_days())
+    }
+
+    /// Compare the version of the release with another one.
+    ///
+    /// The versions are split into numeric and alphabetic segments, e.g.
+    /// `1.2-3.fc34` into `1`, `2`, `3`, `fc` and `34`. Numeric segments are
+    /// compared by their value, alphabetic ones lexically and a numeric
+    /// segment is considered newer than an alphabetic one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The release to compare with.
+    ///
+    /// # Example
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use appstream::builders::ReleaseBuilder;
+    ///
+    /// let older = ReleaseBuilder::new("1.9").build();
+    /// let newer = ReleaseBuilder::new("1.10").build();
+    /// assert_eq!(newer.cmp_version(&older), Ordering::Greater);
+    /// ```
+    pub fn cmp_version(&self, other: &Release) -> Ordering {
+        compare_versions(&self.version, &other.version)
+    }
+}
+
+fn version_segments(version: &str) -> Vec<&str> {
+    let mut segments = Vec::new();
+    for part in version.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut start = 0;
+        for (index, c) in part.char_indices().skip(1) {
+            let previous = part.as_bytes()[index - 1];
+            if previous.is_ascii_digit() != c.is_ascii_digit() {
+                segments.push(&part[start..index]);
+                start = index;
+            }
+        }
+        if start < part.len() {
+            segments.push(&part[start..]);
+        }
+    }
+    segments
+}
+
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (a, b) = (version_segments(a), version_segments(b));
+    for (a, b) in a.iter().zip(b.iter()) {
+        let a_numeric = a.bytes().all(|c| c.is_ascii_digit());
+        let b_numeric = b.bytes().all(|c| c.is_ascii_digit());
+        let ordering = match (a_numeric, b_numeric) {
+            (true, true) => {
+                // Compare the digits without parsing them to not overflow.
+                let (a, b) = (a.trim_start_matches('0'), b.trim_start_matches('0'));
+                a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+            }
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => a.cmp(b),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+fn is_default_urgency(urgency: &ReleaseUrgency) -> bool {
+    *urgency == ReleaseUrgency::default()
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 /// Defines the release artifacts, whether it's the source-code or the binary
 /// distribution. See [\<releases\/\>](https://www.freedesktop.org/software/appstream/docs/chap-Metadata.html#tag-releases).
@@ -76,16 +187,23 @@ pub struct Artifact {
 
 #[cfg(test)]
 mod tests {
-    use std::{convert::TryFrom, error::Error};
+    use std::{cmp::Ordering, convert::TryFrom, error::Error};
 
+    #[cfg(feature = "chrono")]
     use chrono::{TimeZone, Utc};
 
+    #[cfg(not(feature = "chrono"))]
+    use super::DateTime;
+    use super::Release;
+    #[cfg(feature = "chrono")]
     use super::{
-        ArtifactKind, Checksum, MarkupTranslatableString, Release, ReleaseKind, ReleaseUrgency,
-        Size, Url,
+        ArtifactKind, Checksum, MarkupTranslatableString, ReleaseKind, ReleaseUrgency, Size, Url,
     };
-    use crate::builders::{ArtifactBuilder, ReleaseBuilder};
+    use crate::builders::ReleaseBuilder;
+    #[cfg(feature = "chrono")]
+    use crate::{builders::ArtifactBuilder, Component, ParseError};
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn release_artifacts() -> Result<(), Box<dyn Error>> {
         let x = r"
@@ -175,6 +293,204 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn release_translated_description() -> Result<(), Box<dyn Error>> {
+        let x = r"
+        <release version='1.2' date='2014-04-12'>
+          <description>
+            <p>This stable release fixes bugs.</p>
+          </description>
+          <description xml:lang='de'>
+            <p>Diese stabile Version behebt Fehler.</p>
+          </description>
+        </release>";
+
+        let element = xmltree::Element::parse(x.as_bytes())?;
+        let r1 = Release::try_from(&element)?;
+
+        let r2 = ReleaseBuilder::new("1.2")
+            .description(
+                MarkupTranslatableString::with_default("<p>This stable release fixes bugs.</p>")
+                    .and_locale("de", "<p>Diese stabile Version behebt Fehler.</p>"),
+            )
+            .date(Utc.with_ymd_and_hms(2014, 4, 12, 0, 0, 0).unwrap())
+            .build();
+        assert_eq!(r1, r2);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "test_json")]
+    fn release_default_urgency() -> Result<(), Box<dyn Error>> {
+        let x = r"<release version='1.2' date='2014-04-12' />";
+        let element = xmltree::Element::parse(x.as_bytes())?;
+        let r1 = Release::try_from(&element)?;
+        assert!(!r1.is_critical());
+
+        let json = serde_json::to_value(&r1)?;
+        assert!(json.get("urgency").is_none());
+        assert_eq!(serde_json::from_value::<Release>(json)?, r1);
+
+        let x = r"<release version='1.3' date='2014-05-12' urgency='critical' />";
+        let element = xmltree::Element::parse(x.as_bytes())?;
+        let r2 = Release::try_from(&element)?;
+        assert!(r2.is_critical());
+
+        let json = serde_json::to_value(&r2)?;
+        assert_eq!(json["urgency"], "critical");
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn release_bare_text_description() -> Result<(), Box<dyn Error>> {
+        let x = r"
+        <release version='1.2' date='2014-04-12'>
+          <description>
+            Fixed bugs
+          </description>
+          <description xml:lang='de'>Fehler behoben</description>
+        </release>";
+        let element = xmltree::Element::parse(x.as_bytes())?;
+        let r1 = Release::try_from(&element)?;
+
+        let r2 = ReleaseBuilder::new("1.2")
+            .description(
+                MarkupTranslatableString::with_default("<p>Fixed bugs</p>")
+                    .and_locale("de", "<p>Fehler behoben</p>"),
+            )
+            .date(Utc.with_ymd_and_hms(2014, 4, 12, 0, 0, 0).unwrap())
+            .build();
+        assert_eq!(r1, r2);
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn release_details_url() -> Result<(), Box<dyn Error>> {
+        let x = r"
+        <release version='1.2' date='2014-04-12'>
+          <url type='details'>https://example.org/releases/version-1.2.html</url>
+          <url type='unknown'>https://example.org/unknown.html</url>
+        </release>";
+        let element = xmltree::Element::parse(x.as_bytes())?;
+        let r1 = Release::try_from(&element)?;
+
+        let r2 = ReleaseBuilder::new("1.2")
+            .date(Utc.with_ymd_and_hms(2014, 4, 12, 0, 0, 0).unwrap())
+            .url(Url::parse("https://example.org/releases/version-1.2.html")?)
+            .build();
+        assert_eq!(r1, r2);
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn release_timestamp_precedence() -> Result<(), Box<dyn Error>> {
+        let x = r"<release version='1.2' date='2014-04-12' timestamp='1397253600' />";
+        let element = xmltree::Element::parse(x.as_bytes())?;
+        let r1 = Release::try_from(&element)?;
+
+        let r2 = ReleaseBuilder::new("1.2")
+            .date(Utc.timestamp_opt(1397253600, 0).unwrap())
+            .build();
+        assert_eq!(r1, r2);
+
+        let x = r"<release version='1.2' date='2015-04-12' timestamp='1397253600' />";
+        let element = xmltree::Element::parse(x.as_bytes())?;
+        let error = Release::try_from(&element).unwrap_err();
+        assert!(matches!(error, ParseError::Other(tag, _) if tag == "release"));
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn release_milliseconds_timestamp() -> Result<(), Box<dyn Error>> {
+        let x = r"<release version='1.2' timestamp='1424116753000' />";
+        let element = xmltree::Element::parse(x.as_bytes())?;
+        let r1 = Release::try_from(&element)?;
+
+        let r2 = ReleaseBuilder::new("1.2")
+            .date(Utc.timestamp_opt(1424116753, 0).unwrap())
+            .build();
+        assert_eq!(r1, r2);
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn release_eol() -> Result<(), Box<dyn Error>> {
+        let c = Component::from_path("./tests/os.xml".into())?;
+        let now = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+
+        let development = c.release("10.0").unwrap();
+        assert!(!development.is_eol(now));
+        assert_eq!(development.age_days(now), None);
+
+        let stable = c.release("9.0").unwrap();
+        assert!(stable.is_eol(now));
+        assert!(!stable.is_eol(Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap()));
+        assert_eq!(stable.age_days(now), Some(1264));
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn release_self_closing() -> Result<(), Box<dyn Error>> {
+        let x = r"<release version='1.0' date='2020-08-16' date_eol='2021-08-16' urgency='high' type='development'/>";
+        let element = xmltree::Element::parse(x.as_bytes())?;
+        let r1 = Release::try_from(&element)?;
+
+        let r2 = ReleaseBuilder::new("1.0")
+            .date(Utc.with_ymd_and_hms(2020, 8, 16, 0, 0, 0).unwrap())
+            .date_eol(Utc.with_ymd_and_hms(2021, 8, 16, 0, 0, 0).unwrap())
+            .urgency(ReleaseUrgency::High)
+            .kind(ReleaseKind::Development)
+            .build();
+        assert_eq!(r1, r2);
+        assert!(r1.description.is_none());
+        assert!(r1.artifacts.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn release_version_comparison() {
+        let cmp = |a: &str, b: &str| {
+            ReleaseBuilder::new(a)
+                .build()
+                .cmp_version(&ReleaseBuilder::new(b).build())
+        };
+        assert_eq!(cmp("1.2-3.fc34", "1.2-2.fc34"), Ordering::Greater);
+        assert_eq!(cmp("1.2-2.fc34", "1.2-3.fc34"), Ordering::Less);
+        assert_eq!(cmp("1.2-3.fc34", "1.2-3.fc34"), Ordering::Equal);
+        assert_eq!(cmp("1.2-3.fc34", "1.2-3.fc33"), Ordering::Greater);
+        assert_eq!(cmp("1.10", "1.9"), Ordering::Greater);
+        assert_eq!(cmp("1.2.1", "1.2"), Ordering::Greater);
+        assert_eq!(cmp("1.2", "1.2beta"), Ordering::Less);
+        assert_eq!(cmp("3.38.0", "3.38.0"), Ordering::Equal);
+        assert_eq!(
+            cmp("99999999999999999999999.1", "99999999999999999999998.1"),
+            Ordering::Greater
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn release_invalid_date() -> Result<(), Box<dyn Error>> {
+        let x = r"<release version='1.2' date='12 April 2013' />";
+        let element = xmltree::Element::parse(x.as_bytes())?;
+        let error = Release::try_from(&element).unwrap_err();
+
+        assert!(matches!(error, ParseError::Other(ref tag, _) if tag == "release"));
+        assert_eq!(
+            error.to_string(),
+            "Error parsing release: invalid date `12 April 2013`, expected a UNIX timestamp or a date formatted as YYYY-MM-DD"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
     #[test]
     fn release_size() -> Result<(), Box<dyn Error>> {
         let x = r"
@@ -204,17 +520,33 @@ mod tests {
             vec![
                 ReleaseBuilder::new("1.8")
                     .description(MarkupTranslatableString::with_default("<p>This stable release fixes the following bug:</p><ul><li>CPU no longer overheats when you hold down spacebar</li></ul>"))
-                    .date(Utc.datetime_from_str("1424116753", "%s")?)
+                    .date(Utc.timestamp_opt(1424116753, 0).unwrap())
                     .sizes(vec![Size::Download(12345678), Size::Installed(42424242)])
                     .build(),
                 ReleaseBuilder::new("1.2")
-                    .date(Utc.datetime_from_str("1397253600", "%s")?)
+                    .date(Utc.timestamp_opt(1397253600, 0).unwrap())
                     .build(),
                 ReleaseBuilder::new("1.0")
-                    .date(Utc.datetime_from_str("1345932000", "%s")?)
+                    .date(Utc.timestamp_opt(1345932000, 0).unwrap())
                     .build()
             ]
         );
         Ok(())
     }
+
+    #[cfg(not(feature = "chrono"))]
+    #[test]
+    fn release_raw_date() -> Result<(), Box<dyn Error>> {
+        let x = r"<release version='1.2' date='16 February 2015' date_eol='1424116753' />";
+
+        let element = xmltree::Element::parse(x.as_bytes())?;
+        let r1 = Release::try_from(&element)?;
+
+        let r2 = ReleaseBuilder::new("1.2")
+            .date(DateTime("16 February 2015".into()))
+            .date_eol(DateTime("1424116753".into()))
+            .build();
+        assert_eq!(r1, r2);
+        Ok(())
+    }
 }
@@ -19,35 +19,75 @@ pub enum Requirement {
     Other,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+impl Requirement {
+    /// Creates a display length requirement using the default comparison
+    /// (`ge`) and side (`shortest`).
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::{DisplayLength, DisplayLengthValue, Rel, Requirement, Side};
+    ///
+    /// let requirement = Requirement::display_length(DisplayLengthValue::Value(360));
+    /// assert_eq!(
+    ///     requirement,
+    ///     Requirement::DisplayLength(DisplayLength {
+    ///         compare: Rel::Ge,
+    ///         value: DisplayLengthValue::Value(360),
+    ///         side: Side::Shortest,
+    ///     })
+    /// );
+    /// ```
+    pub fn display_length(value: DisplayLengthValue) -> Self {
+        Self::DisplayLength(DisplayLength {
+            compare: Rel::default(),
+            value,
+            side: Side::default(),
+        })
+    }
+
+    /// Creates a control requirement.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::{Control, Requirement};
+    ///
+    /// let requirement = Requirement::control(Control::Keyboard);
+    /// assert_eq!(requirement, Requirement::Control(Control::Keyboard));
+    /// ```
+    pub fn control(control: Control) -> Self {
+        Self::Control(control)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+/// The comparison operator used by a display length requirement.
 pub enum Rel {
+    /// Equal to.
     Eq,
+    /// Not equal to.
     Ne,
+    /// Less than.
     Lt,
+    /// Greater than.
     Gt,
+    /// Less than or equal to.
     Le,
+    /// Greater than or equal to.
+    #[default]
     Ge,
 }
 
-impl Default for Rel {
-    fn default() -> Self {
-        Self::Ge
-    }
-}
-
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 #[serde(rename_all = "lowercase")]
+/// The side of the display a display length requirement is measured on.
 pub enum Side {
+    /// The longest side of the display.
     Longest,
+    /// The shortest side of the display.
+    #[default]
     Shortest,
 }
 
-impl Default for Side {
-    fn default() -> Self {
-        Self::Shortest
-    }
-}
-
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 /// A display length requirement
 pub struct DisplayLength {
@@ -8,7 +8,9 @@ use super::{enums::ImageKind, TranslatableString};
 /// See [\<screenshots\/\>](https://www.freedesktop.org/software/appstream/docs/chap-Metadata.html#tag-screenshots).
 pub struct Screenshot {
     #[serde(default, alias = "default")]
-    /// Whether the current screenshot is the default one.
+    /// Whether the current screenshot is the default one. When parsing a
+    /// component, the first screenshot is the default one unless another
+    /// one has a `type="default"` attribute.
     pub is_default: bool,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -31,6 +33,76 @@ pub struct Screenshot {
     pub videos: Vec<Video>,
 }
 
+impl Screenshot {
+    /// Retrieve the caption for a specific locale, falling back to the
+    /// untranslated one if no translation is available.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - The locale to retrieve the caption for.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::Component;
+    ///
+    /// let component = Component::from_path("./tests/desktop.xml".into()).unwrap();
+    /// let screenshot = component.default_screenshot().unwrap();
+    /// assert_eq!(
+    ///     screenshot.caption_for_locale("de").map(String::as_str),
+    ///     Some("The options dialog")
+    /// );
+    /// ```
+    pub fn caption_for_locale(&self, locale: &str) -> Option<&String> {
+        let caption = self.caption.as_ref()?;
+        caption
+            .get_for_locale(locale)
+            .or_else(|| caption.get_default())
+    }
+
+    /// Retrieve the best available caption for a specific locale, falling
+    /// back to the language without its territory, e.g. `de` for `de_DE`,
+    /// and then to the untranslated caption.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - The locale to retrieve the caption for.
+    pub fn caption_best(&self, locale: &str) -> Option<&String> {
+        self.caption.as_ref()?.get_best(locale)
+    }
+
+    /// The source image of the screenshot, if any.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::Component;
+    ///
+    /// let component =
+    ///     Component::from_path("./tests/app-org.gnome.design.Contrast.xml".into()).unwrap();
+    /// let screenshot = component.default_screenshot().unwrap();
+    /// assert!(screenshot
+    ///     .source()
+    ///     .unwrap()
+    ///     .url
+    ///     .as_str()
+    ///     .ends_with("screenshot1.png"));
+    ///
+    /// let thumbnails = screenshot.thumbnails();
+    /// assert_eq!(thumbnails.len(), 4);
+    /// assert_eq!(thumbnails[0].width, Some(624));
+    /// ```
+    pub fn source(&self) -> Option<&Image> {
+        self.images.iter().find(|i| i.kind == ImageKind::Source)
+    }
+
+    /// The thumbnail images of the screenshot, in the order they are listed.
+    pub fn thumbnails(&self) -> Vec<&Image> {
+        self.images
+            .iter()
+            .filter(|i| i.kind == ImageKind::Thumbnail)
+            .collect()
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 /// A screenshot video.
 /// See [\<screenshots\/\>](https://www.freedesktop.org/software/appstream/docs/chap-Metadata.html#tag-screenshots).
@@ -51,10 +123,35 @@ pub struct Video {
     /// The video container. Possible values are Matroska(.mkv) or WebM.
     pub container: Option<String>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// A translatable small description of the video, set by a `<caption>`
+    /// inside of the `<video>`. The caption of the screenshot it belongs to
+    /// is not copied over.
+    pub caption: Option<TranslatableString>,
+
     /// The video url.
     pub url: Url,
 }
 
+impl Video {
+    /// Whether the video uses one of the containers (WebM or Matroska) and
+    /// codecs (VP9 or AV1) allowed by the specifications.
+    ///
+    /// Unset container or codec are not considered as a violation.
+    pub fn is_spec_compliant(&self) -> bool {
+        let supported_container = self
+            .container
+            .as_ref()
+            .is_none_or(|c| matches!(c.to_lowercase().as_str(), "webm" | "matroska" | "mkv"));
+        let supported_codec = self
+            .codec
+            .as_ref()
+            .is_none_or(|c| matches!(c.to_lowercase().as_str(), "vp9" | "av1"));
+
+        supported_container && supported_codec
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 /// A screenshot image.
 /// See [\<screenshots\/\>](https://www.freedesktop.org/software/appstream/docs/chap-Metadata.html#tag-screenshots).
@@ -71,6 +168,10 @@ pub struct Image {
     /// The image height.
     pub height: Option<u32>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// The image scale factor, for HiDPI screens.
+    pub scale: Option<u32>,
+
     /// The image url.
     pub url: Url,
 }
@@ -80,7 +181,10 @@ mod tests {
     use std::{convert::TryFrom, error::Error};
 
     use super::*;
-    use crate::builders::{ImageBuilder, ScreenshotBuilder, VideoBuilder};
+    use crate::{
+        builders::{ImageBuilder, ScreenshotBuilder, VideoBuilder},
+        Component,
+    };
 
     #[test]
     fn default_screenshot() -> Result<(), Box<dyn Error>> {
@@ -146,6 +250,63 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn screenshot_caption_markup() -> Result<(), Box<dyn Error>> {
+        let xml = r"
+        <screenshot type='default'>
+            <caption>FooBar showing <em>bold</em> functionality.</caption>
+            <image type='source'>https://www.example.org/en_US/main.png</image>
+        </screenshot>";
+
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let s1 = Screenshot::try_from(&element)?;
+
+        let s2 = ScreenshotBuilder::default()
+            .caption(TranslatableString::with_default(
+                "FooBar showing bold functionality.",
+            ))
+            .image(ImageBuilder::new(Url::parse("https://www.example.org/en_US/main.png")?).build())
+            .build();
+        assert_eq!(s1, s2);
+        Ok(())
+    }
+
+    #[test]
+    fn screenshot_image_scale() -> Result<(), Box<dyn Error>> {
+        let xml = r"<image type='thumbnail' scale='2' width='400' height='300'>https://www.example.org/en_US/main@2.png</image>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let i1 = Image::try_from(&element)?;
+
+        let i2 = ImageBuilder::new(Url::parse("https://www.example.org/en_US/main@2.png")?)
+            .kind(ImageKind::Thumbnail)
+            .scale(2)
+            .width(400)
+            .height(300)
+            .build();
+        assert_eq!(i1, i2);
+        Ok(())
+    }
+
+    #[test]
+    fn screenshot_type() -> Result<(), Box<dyn Error>> {
+        let xml = r"
+            <screenshot type='extra'>
+                <image type='source'>https://www.example.org/en_US/main.png</image>
+            </screenshot>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let s1 = Screenshot::try_from(&element)?;
+        assert!(!s1.is_default);
+
+        let xml = r"
+            <screenshot type='foobar'>
+                <image type='source'>https://www.example.org/en_US/main.png</image>
+            </screenshot>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let s2 = Screenshot::try_from(&element)?;
+        assert!(!s2.is_default);
+        Ok(())
+    }
+
     #[test]
     fn screenshot_video() -> Result<(), Box<dyn Error>> {
         let xml = r"
@@ -168,4 +329,100 @@ mod tests {
         assert_eq!(s1, s2);
         Ok(())
     }
+
+    #[test]
+    fn screenshot_video_caption() -> Result<(), Box<dyn Error>> {
+        let xml = r"
+            <screenshot type='default'>
+                <caption>A screenshot of FooBar</caption>
+                <video container='webm' codec='vp9'>
+                    https://example.com/foobar/screencast.webm
+                    <caption>A screencast of FooBar</caption>
+                    <caption xml:lang='de'>Eine Bildschirmaufnahme von FooBar</caption>
+                </video>
+                <video container='webm' codec='vp9'>https://example.com/foobar/screencast-2.webm</video>
+            </screenshot>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let s1 = Screenshot::try_from(&element)?;
+
+        let caption = TranslatableString::with_default("A screencast of FooBar")
+            .and_locale("de", "Eine Bildschirmaufnahme von FooBar");
+        let s2 = ScreenshotBuilder::default()
+            .caption(TranslatableString::with_default("A screenshot of FooBar"))
+            .video(
+                VideoBuilder::new(Url::parse("https://example.com/foobar/screencast.webm")?)
+                    .container("webm")
+                    .codec("vp9")
+                    .caption(caption.clone())
+                    .build(),
+            )
+            .video(
+                VideoBuilder::new(Url::parse("https://example.com/foobar/screencast-2.webm")?)
+                    .container("webm")
+                    .codec("vp9")
+                    .build(),
+            )
+            .build();
+        assert_eq!(s1, s2);
+        assert_eq!(s1.videos[0].caption, Some(caption));
+        assert_eq!(s1.videos[1].caption, None);
+        Ok(())
+    }
+
+    #[test]
+    fn video_spec_compliance() -> Result<(), Box<dyn Error>> {
+        let url = Url::parse("https://example.com/foobar/screencast.mkv")?;
+        let video = VideoBuilder::new(url.clone())
+            .container("mkv")
+            .codec("av1")
+            .build();
+        assert!(video.is_spec_compliant());
+
+        let video = VideoBuilder::new(url)
+            .container("mp4")
+            .codec("h264")
+            .build();
+        assert!(!video.is_spec_compliant());
+        Ok(())
+    }
+
+    #[test]
+    fn screenshot_caption_best() -> Result<(), Box<dyn Error>> {
+        let component = Component::from_path("./tests/desktop.xml".into())?;
+        let screenshot = component.default_screenshot().unwrap();
+        assert_eq!(
+            screenshot.caption_best("de_DE").map(String::as_str),
+            Some("The options dialog")
+        );
+
+        let xml = r"
+            <screenshot type='default'>
+                <caption>The main window</caption>
+                <caption xml:lang='de'>Das Hauptfenster</caption>
+                <image>https://example.com/main.png</image>
+            </screenshot>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let screenshot = Screenshot::try_from(&element)?;
+        assert_eq!(
+            screenshot.caption_best("de_DE").map(String::as_str),
+            Some("Das Hauptfenster")
+        );
+        assert_eq!(
+            screenshot.caption_best("fr_FR").map(String::as_str),
+            Some("The main window")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn image_type_tolerance() -> Result<(), Box<dyn Error>> {
+        let xml = r"<image type='Thumbnail' width='112' height='63'>https://example.com/thumb.png</image>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        assert_eq!(Image::try_from(&element)?.kind, ImageKind::Thumbnail);
+
+        let xml = r"<image type='screenshot'>https://example.com/main.png</image>";
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        assert_eq!(Image::try_from(&element)?.kind, ImageKind::Source);
+        Ok(())
+    }
 }
@@ -1,9 +1,46 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, fmt};
 
 use serde::{Deserialize, Serialize};
 
 pub const DEFAULT_LOCALE: &str = "C";
 
+/// Normalize a locale to its POSIX form, e.g. `pt-BR` becomes `pt_BR`.
+///
+/// The `-` separator of BCP-47 locales is replaced with a `_` and the
+/// language part is lowercased. The default locale `C` is kept as is.
+///
+/// # Example
+/// ```
+/// use appstream::normalize_locale;
+///
+/// assert_eq!(normalize_locale("pt-BR"), "pt_BR");
+/// assert_eq!(normalize_locale("DE"), "de");
+/// assert_eq!(normalize_locale("C"), "C");
+/// ```
+pub fn normalize_locale(locale: &str) -> String {
+    if locale == DEFAULT_LOCALE {
+        return locale.to_string();
+    }
+    let locale = locale.replace('-', "_");
+    match locale.find(['_', '.', '@']) {
+        Some(index) => format!("{}{}", locale[..index].to_lowercase(), &locale[index..]),
+        None => locale.to_lowercase(),
+    }
+}
+
+/// Retrieve the best matching value for a locale: the locale itself, then
+/// its language only, then the default locale.
+fn get_best<'a, T>(map: &'a BTreeMap<String, T>, locale: &str) -> Option<&'a T> {
+    let locale = normalize_locale(locale);
+    map.get(&locale)
+        .or_else(|| {
+            locale
+                .find(['_', '.', '@'])
+                .and_then(|index| map.get(&locale[..index]))
+        })
+        .or_else(|| map.get(DEFAULT_LOCALE))
+}
+
 fn element_to_xml(e: &xmltree::Element) -> String {
     e.children
         .iter()
@@ -18,6 +55,18 @@ fn element_to_xml(e: &xmltree::Element) -> String {
         .join("")
 }
 
+fn element_to_text(e: &xmltree::Element) -> String {
+    e.children
+        .iter()
+        .map(|node| match node {
+            xmltree::XMLNode::Element(ref c) => element_to_text(c),
+            xmltree::XMLNode::Text(t) | xmltree::XMLNode::CData(t) => t.clone(),
+            _ => "".to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join("")
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 /// A wrapper around a translable string that can contains markup.
 ///
@@ -61,10 +110,33 @@ impl MarkupTranslatableString {
     /// Adds a new string from a `xmltree.Element`
     ///
     /// XML elements containing a `lang` attribute are marked as translatable
-    /// and can be used to feed the `MarkupTranslatableString`.
+    /// and can be used to feed the `MarkupTranslatableString`. When the
+    /// children of the element, like its paragraphs, are translated one by
+    /// one instead, they are grouped by their own `lang` attribute.
     pub fn add_for_element(&mut self, element: &xmltree::Element) {
         let locale = element.attributes.get("lang").map(|l| l.as_str());
-        self.add_for_locale(locale, &element_to_xml(element));
+        let children = element.children.iter().filter_map(|node| node.as_element());
+        if !children.clone().any(|c| c.attributes.contains_key("lang")) {
+            self.add_for_locale(locale, &element_to_xml(element));
+            return;
+        }
+
+        let mut translations: BTreeMap<Option<&str>, String> = BTreeMap::new();
+        for child in children {
+            let child_locale = child.attributes.get("lang").map(|l| l.as_str()).or(locale);
+            translations
+                .entry(child_locale)
+                .or_default()
+                .push_str(&format!(
+                    "<{}>{}</{}>",
+                    child.name,
+                    element_to_xml(child),
+                    child.name
+                ));
+        }
+        for (locale, text) in translations {
+            self.add_for_locale(locale, &text);
+        }
     }
 
     /// Adds a new translation for a speicifc locale.
@@ -76,7 +148,7 @@ impl MarkupTranslatableString {
     /// * `text` - The translation corresponding to the locale.
     pub fn add_for_locale(&mut self, locale: Option<&str>, text: &str) {
         self.0.insert(
-            locale.unwrap_or(DEFAULT_LOCALE).to_string(),
+            locale.map_or_else(|| DEFAULT_LOCALE.to_string(), normalize_locale),
             text.to_string(),
         );
     }
@@ -86,13 +158,39 @@ impl MarkupTranslatableString {
         self.0.get(DEFAULT_LOCALE)
     }
 
+    /// Consumes `self` and returns the text corresponding to the default
+    /// locale `C`.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::MarkupTranslatableString;
+    ///
+    /// let description = MarkupTranslatableString::with_default("<p>Fixed bugs</p>")
+    ///     .and_locale("de", "<p>Fehler behoben</p>");
+    /// assert_eq!(description.into_default().as_deref(), Some("<p>Fixed bugs</p>"));
+    /// ```
+    pub fn into_default(mut self) -> Option<String> {
+        self.0.remove(DEFAULT_LOCALE)
+    }
+
     /// Retrieve the corresponding text for a specific locale if available.
     ///
     /// # Arguments
     ///
     /// * `locale` - The locale to retrieve the text for.
     pub fn get_for_locale(&self, locale: &str) -> Option<&String> {
-        self.0.get(locale)
+        self.0.get(&normalize_locale(locale))
+    }
+
+    /// Retrieve the best available text for a specific locale, falling back
+    /// to the language without its territory, e.g. `pt` for `pt_BR`, and
+    /// then to the default locale.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - The locale to retrieve the text for.
+    pub fn get_best(&self, locale: &str) -> Option<&String> {
+        get_best(&self.0, locale)
     }
 
     /// Whether `self` contains any translatable strings.
@@ -101,6 +199,13 @@ impl MarkupTranslatableString {
     }
 }
 
+impl fmt::Display for MarkupTranslatableString {
+    /// Formats the text of the default locale, or nothing if there is none.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.get_default().map_or("", |t| t.as_str()))
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 /// A wrapper around a translatable string.
 ///
@@ -143,13 +248,15 @@ impl TranslatableString {
     /// Adds a new string from a `xmltree.Element`
     ///
     /// XML elements containing a `lang` attribute are marked as translatable
-    /// and can be used to feed the `TranslatableString`.
+    /// and can be used to feed the `TranslatableString`. Any stray markup is
+    /// dropped and only the text it wraps is kept, with its whitespace runs
+    /// collapsed into single spaces as it's a single-line text.
     pub fn add_for_element(&mut self, element: &xmltree::Element) {
-        self.add_for_locale(
-            element.attributes.get("lang").map(|l| l.as_str()),
-            &element.get_text().unwrap_or_default(), /* for some reason some description tags
-                                                      * contains empty strings. */
-        );
+        let text = element_to_text(element)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.add_for_locale(element.attributes.get("lang").map(|l| l.as_str()), &text);
     }
 
     /// Adds a new translation for a speicifc locale.
@@ -161,7 +268,7 @@ impl TranslatableString {
     /// * `text` - The translation corresponding to the locale.
     pub fn add_for_locale(&mut self, locale: Option<&str>, text: &str) {
         self.0.insert(
-            locale.unwrap_or(DEFAULT_LOCALE).to_string(),
+            locale.map_or_else(|| DEFAULT_LOCALE.to_string(), normalize_locale),
             text.to_string(),
         );
     }
@@ -171,13 +278,38 @@ impl TranslatableString {
         self.0.get(DEFAULT_LOCALE)
     }
 
+    /// Consumes `self` and returns the text corresponding to the default
+    /// locale `C`.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::TranslatableString;
+    ///
+    /// let name = TranslatableString::with_default("Contrast").and_locale("de", "Kontrast");
+    /// assert_eq!(name.into_default().as_deref(), Some("Contrast"));
+    /// ```
+    pub fn into_default(mut self) -> Option<String> {
+        self.0.remove(DEFAULT_LOCALE)
+    }
+
     /// Retrieve the corresponding text for a specific locale if available.
     ///
     /// # Arguments
     ///
     /// * `locale` - The locale to retrieve the text for.
     pub fn get_for_locale(&self, locale: &str) -> Option<&String> {
-        self.0.get(locale)
+        self.0.get(&normalize_locale(locale))
+    }
+
+    /// Retrieve the best available text for a specific locale, falling back
+    /// to the language without its territory, e.g. `pt` for `pt_BR`, and
+    /// then to the default locale.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - The locale to retrieve the text for.
+    pub fn get_best(&self, locale: &str) -> Option<&String> {
+        get_best(&self.0, locale)
     }
 
     /// Whether `self` contains any translatable strings.
@@ -186,6 +318,22 @@ impl TranslatableString {
     }
 }
 
+impl fmt::Display for TranslatableString {
+    /// Formats the text of the default locale, or nothing if there is none.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::TranslatableString;
+    ///
+    /// let name = TranslatableString::with_default("Contrast").and_locale("de", "Kontrast");
+    /// println!("{}", name);
+    /// assert_eq!(name.to_string(), "Contrast");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.get_default().map_or("", |t| t.as_str()))
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 /// A wrapper around a list of strings that are translatable.
 ///
@@ -250,15 +398,114 @@ impl TranslatableList {
     /// * `text` - The string to add.
     pub fn add_for_locale(&mut self, locale: Option<&str>, text: &str) {
         self.0
-            .entry(locale.unwrap_or(DEFAULT_LOCALE).into())
+            .entry(locale.map_or_else(|| DEFAULT_LOCALE.to_string(), normalize_locale))
             .and_modify(|sentenses| {
                 sentenses.push(text.into());
             })
             .or_insert_with(|| vec![text.to_string()]);
     }
 
+    /// Removes the duplicated strings of each locale, keeping the first
+    /// occurrence.
+    pub fn dedup_locale(&mut self) {
+        for words in self.0.values_mut() {
+            let mut seen = std::collections::HashSet::new();
+            words.retain(|w| seen.insert(w.clone()));
+        }
+    }
+
+    /// The locales with a list of strings, the default locale being `C`.
+    ///
+    /// # Example
+    /// ```
+    /// use appstream::TranslatableList;
+    ///
+    /// let keywords = TranslatableList::with_default(vec!["Color", "Contrast"])
+    ///     .and_locale("de", vec!["Farbe", "Kontrast"])
+    ///     .and_locale("cs", vec!["barva"]);
+    /// assert_eq!(keywords.locales().collect::<Vec<_>>(), vec!["C", "cs", "de"]);
+    ///
+    /// let counts = keywords
+    ///     .iter()
+    ///     .map(|(locale, words)| (locale, words.len()))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(counts, vec![("C", 2), ("cs", 1), ("de", 2)]);
+    /// ```
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Iterate over the locales and their list of strings, sorted by locale.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Vec<String>)> {
+        self.0
+            .iter()
+            .map(|(locale, words)| (locale.as_str(), words))
+    }
+
     /// Whether `self` contains any translatable strings.
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_locale, MarkupTranslatableString, TranslatableList, TranslatableString};
+
+    #[test]
+    fn locale_normalization() {
+        assert_eq!(normalize_locale("pt_BR"), "pt_BR");
+        assert_eq!(normalize_locale("pt-BR"), "pt_BR");
+        assert_eq!(normalize_locale("PT-BR"), "pt_BR");
+        assert_eq!(normalize_locale("sr@latin"), "sr@latin");
+        assert_eq!(normalize_locale("C"), "C");
+
+        let name = TranslatableString::with_default("Contrast")
+            .and_locale("pt_BR", "Contraste")
+            .and_locale("de-DE", "Kontrast");
+        assert_eq!(name.get_best("pt-BR"), Some(&"Contraste".to_string()));
+        assert_eq!(name.get_for_locale("de_DE"), Some(&"Kontrast".to_string()));
+        assert_eq!(name.get_best("fr"), Some(&"Contrast".to_string()));
+
+        let keywords = TranslatableList::default().and_locale("pt-BR", vec!["Cor"]);
+        assert!(keywords.0.contains_key("pt_BR"));
+    }
+
+    #[test]
+    fn display_default_locale() {
+        let name = TranslatableString::with_default("Contrast").and_locale("de", "Kontrast");
+        assert_eq!(format!("{}", name), "Contrast");
+
+        let name = TranslatableString::default().and_locale("de", "Kontrast");
+        assert_eq!(name.to_string(), "");
+        assert_eq!(name.into_default(), None);
+    }
+
+    #[test]
+    fn best_locale_language_fallback() {
+        let name = TranslatableString::with_default("Contrast").and_locale("de", "Kontrast");
+        assert_eq!(name.get_best("de_AT"), Some(&"Kontrast".to_string()));
+        assert_eq!(name.get_best("de-CH"), Some(&"Kontrast".to_string()));
+    }
+
+    #[test]
+    fn translated_paragraphs() -> Result<(), Box<dyn std::error::Error>> {
+        let xml = r#"
+            <description>
+                <p>English</p>
+                <p xml:lang="de">Deutsch</p>
+                <p>More English</p>
+                <p xml:lang="de">Mehr Deutsch</p>
+            </description>"#;
+        let element = xmltree::Element::parse(xml.as_bytes())?;
+        let mut description = MarkupTranslatableString::default();
+        description.add_for_element(&element);
+
+        assert_eq!(
+            description,
+            MarkupTranslatableString::with_default("<p>English</p><p>More English</p>")
+                .and_locale("de", "<p>Deutsch</p><p>Mehr Deutsch</p>")
+        );
+        Ok(())
+    }
+}
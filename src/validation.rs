@@ -0,0 +1,67 @@
+use std::fmt;
+
+use url::Url;
+
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+/// A non fatal issue found while validating a `Component` against the
+/// specifications.
+pub enum ValidationWarning {
+    /// A screenshot video doesn't use a WebM/Matroska container with a VP9/AV1
+    /// codec.
+    UnsupportedVideo(Url),
+    /// Multiple releases share the same version.
+    DuplicateReleaseVersion(String),
+    /// The component has no `<id>`, only returned by
+    /// `Component::try_from_partial` and `Component::validate_element`.
+    MissingId,
+    /// The component has no `type` while its `.desktop` launchable implies a
+    /// `desktop-application`.
+    MissingComponentType,
+    /// Multiple `<description>` tags share the same locale, only returned by
+    /// `Component::validate_element`.
+    DuplicateDescription(String),
+    /// A `<screenshot>` has a `type` other than `default` or `extra`, only
+    /// returned by `Component::validate_element`.
+    UnknownScreenshotType(String),
+    /// An `<image>` has a `type` other than `source` or `thumbnail` and is
+    /// considered to be the source image, only returned by
+    /// `Component::validate_element`.
+    UnknownImageType(String),
+    /// A `<provides>` child is not recognized and is kept as a
+    /// `Provide::Unknown`, only returned by `Component::try_from_partial` and
+    /// `Component::validate_element`.
+    UnknownProvide(String),
+}
+
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVideo(url) => write!(
+                f,
+                "The video {} doesn't use a supported container or codec",
+                url
+            ),
+            Self::DuplicateReleaseVersion(version) => {
+                write!(f, "The release {} is listed multiple times", version)
+            }
+            Self::MissingId => write!(f, "The component doesn't have an id"),
+            Self::DuplicateDescription(locale) => {
+                write!(f, "The description is listed multiple times for {}", locale)
+            }
+            Self::UnknownScreenshotType(kind) => {
+                write!(f, "The screenshot type {} is not supported", kind)
+            }
+            Self::UnknownImageType(kind) => {
+                write!(f, "The image type {} is not supported", kind)
+            }
+            Self::UnknownProvide(tag) => {
+                write!(f, "The provided <{}> is not supported", tag)
+            }
+            Self::MissingComponentType => write!(
+                f,
+                "The component has a desktop launchable but no desktop-application type"
+            ),
+        }
+    }
+}
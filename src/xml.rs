@@ -1,6 +1,7 @@
 use std::{convert::TryFrom, str::FromStr};
 
-use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use url::Url;
 use xmltree::{Element, XMLNode};
 
@@ -10,27 +11,51 @@ use super::{
         ScreenshotBuilder, VideoBuilder,
     },
     enums::{
-        ArtifactKind, Bundle, Category, Checksum, ComponentKind, ContentAttribute,
-        ContentRatingVersion, ContentState, FirmwareKind, Icon, ImageKind, Kudo, Launchable,
-        ProjectUrl, Provide, ReleaseKind, ReleaseUrgency, Size, Translation,
+        ArtifactKind, Bundle, Category, Checksum, ColorKind, ColorScheme, ComponentKind,
+        ContentAttribute, ContentRatingVersion, ContentState, Icon, ImageKind, Kudo, Launchable,
+        MergeKind, ProjectUrl, Provide, ReleaseKind, ReleaseOrder, ReleaseUrgency, Size,
+        Translation,
     },
     error::{CollectionParseError, ContextParseError, ParseError},
     requirements::{Control, DisplayLength, DisplayLengthValue, Rel, Side},
-    AppId, Artifact, Collection, Component, ContentRating, Image, Language, License,
-    MarkupTranslatableString, Release, Requirement, Screenshot, TranslatableList,
-    TranslatableString, Video,
+    AppId, Artifact, Branding, BrandingColor, Collection, Component, ContentRating, DateTime,
+    Image, Language, License, MarkupTranslatableString, Release, Requirement, Screenshot,
+    TranslatableList, TranslatableString, ValidationWarning, Video,
 };
 
-fn deserialize_date(date: &str) -> Result<DateTime<Utc>, chrono::ParseError> {
-    Utc.datetime_from_str(date, "%s").or_else(
-        |_: chrono::ParseError| -> Result<DateTime<Utc>, chrono::ParseError> {
-            let date = NaiveDateTime::new(
-                NaiveDate::parse_from_str(date, "%Y-%m-%d")?,
-                NaiveTime::default(),
-            );
-            Ok(DateTime::<Utc>::from_utc(date, Utc))
-        },
-    )
+#[cfg(feature = "chrono")]
+fn deserialize_date(date: &str, attr: &str) -> Result<DateTime, ParseError> {
+    // Some non-conforming files use a 13-digit timestamp in milliseconds,
+    // dropping the last three digits converts it to seconds.
+    let date = if date.len() == 13 && date.bytes().all(|b| b.is_ascii_digit()) {
+        &date[..10]
+    } else {
+        date
+    };
+    NaiveDateTime::parse_from_str(date, "%s")
+        .or_else(
+            |_: chrono::ParseError| -> Result<NaiveDateTime, chrono::ParseError> {
+                Ok(NaiveDateTime::new(
+                    NaiveDate::parse_from_str(date, "%Y-%m-%d")?,
+                    NaiveTime::default(),
+                ))
+            },
+        )
+        .map(|date| date.and_utc())
+        .map_err(|_| {
+            ParseError::other(
+                "release",
+                &format!(
+                    "invalid {} `{}`, expected a UNIX timestamp or a date formatted as YYYY-MM-DD",
+                    attr, date
+                ),
+            )
+        })
+}
+
+#[cfg(not(feature = "chrono"))]
+fn deserialize_date(date: &str, _attr: &str) -> Result<DateTime, ParseError> {
+    Ok(DateTime(date.to_string()))
 }
 
 impl TryFrom<&Element> for AppId {
@@ -86,6 +111,61 @@ impl TryFrom<&Element> for Artifact {
     }
 }
 
+impl TryFrom<&Element> for Branding {
+    type Error = ParseError;
+
+    fn try_from(e: &Element) -> Result<Self, Self::Error> {
+        let mut branding = Branding::default();
+        for child in e.children.iter() {
+            match child {
+                XMLNode::Element(element) if element.name == "color" => {
+                    branding.colors.push(BrandingColor::try_from(element)?);
+                }
+                _ => (),
+            }
+        }
+        Ok(branding)
+    }
+}
+
+impl TryFrom<&Element> for BrandingColor {
+    type Error = ParseError;
+
+    fn try_from(e: &Element) -> Result<Self, Self::Error> {
+        let value = e
+            .get_text()
+            .ok_or_else(|| ParseError::missing_value("color"))?
+            .trim()
+            .to_string();
+        if !BrandingColor::is_valid_hex(&value) {
+            return Err(ParseError::invalid_value(&value, "$value", "color"));
+        }
+
+        let kind = match e.attributes.get("type") {
+            Some(t) => {
+                ColorKind::from_str(t).map_err(|_| ParseError::invalid_value(t, "type", "color"))?
+            }
+            None => return Err(ParseError::missing_attribute("type", "color")),
+        };
+
+        // A color without a scheme preference can be used with any scheme.
+        let scheme_preference = e
+            .attributes
+            .get("scheme_preference")
+            .map(|s| {
+                ColorScheme::from_str(s)
+                    .map_err(|_| ParseError::invalid_value(s, "scheme_preference", "color"))
+            })
+            .transpose()?;
+
+        Ok(BrandingColor {
+            kind,
+            scheme_preference,
+            value,
+        })
+    }
+}
+
 impl TryFrom<&Element> for Bundle {
     type Error = ParseError;
 
@@ -98,7 +178,10 @@ impl TryFrom<&Element> for Bundle {
         match e.attributes.get("type") {
             Some(t) => match t.as_str() {
                 "tarball" => Ok(Bundle::Tarball(val)),
-                "snap" => Ok(Bundle::Snap(val)),
+                "snap" => Ok(Bundle::Snap {
+                    id: val,
+                    channel: e.attributes.get("channel").map(|c| c.to_string()),
+                }),
                 "appimage" => Ok(Bundle::AppImage(val)),
                 "limba" => Ok(Bundle::Limba(val)),
                 "flatpak" => Ok(Bundle::Flatpak {
@@ -185,219 +268,349 @@ impl TryFrom<&Element> for Collection {
 impl TryFrom<&Element> for Component {
     type Error = ParseError;
     fn try_from(e: &Element) -> Result<Self, Self::Error> {
-        let mut component = ComponentBuilder::default();
+        parse_component(e, true, false).map(|(component, _)| component)
+    }
+}
 
-        if let Some(kind) = e.attributes.get("type") {
-            component = component.kind(
-                ComponentKind::from_str(kind.as_str())
-                    .map_err(|_| ParseError::invalid_value(kind, "type", "component"))?,
-            );
-        }
+/// Parses a `<component>`, a missing `<id>` is left empty unless `require_id`
+/// is set, see `Component::try_from_partial`.
+///
+/// In `strict` mode, the data that would otherwise be dropped or kept as is
+/// is rejected instead, see `Component::try_from_strict`. The tolerated
+/// issues are returned alongside the component.
+pub(crate) fn parse_component(
+    e: &Element,
+    require_id: bool,
+    strict: bool,
+) -> Result<(Component, Vec<ValidationWarning>), ParseError> {
+    // A catalog wrapping a single component is accepted as well.
+    if e.name == "components" {
+        let mut components = e.children.iter().filter_map(|node| match node {
+            XMLNode::Element(c) if c.name == "component" => Some(c),
+            _ => None,
+        });
+        return match (components.next(), components.next()) {
+            (Some(c), None) => parse_component(c, require_id, strict),
+            (None, _) => Err(ParseError::missing_tag("component")),
+            (Some(_), Some(_)) => Err(ParseError::other(
+                "components",
+                "expected a single component, use a Collection instead",
+            )),
+        };
+    }
 
-        let app_id = AppId::try_from(
-            e.get_child("id")
-                .ok_or_else(|| ParseError::missing_tag("id"))?,
-        )?;
+    let mut component = ComponentBuilder::default();
+    let mut warnings = Vec::new();
 
-        let mut name = TranslatableString::default();
-        let mut summary = TranslatableString::default();
-        let mut developer_name = TranslatableString::default();
-        let mut keywords = TranslatableList::default();
-        let mut description = MarkupTranslatableString::default();
-        for node in &e.children {
-            if let xmltree::XMLNode::Element(ref e) = node {
-                match &*e.name {
-                    "name" => name.add_for_element(e),
-                    "summary" => summary.add_for_element(e),
-                    "developer_name" => developer_name.add_for_element(e),
-                    "description" => description.add_for_element(e),
-                    "project_license" => {
-                        component = component.project_license(License::try_from(e)?);
-                    }
-                    "metadata_license" => {
-                        component = component.metadata_license(License::try_from(e)?);
-                    }
-                    "icon" => {
-                        component = component.icon(Icon::try_from(e)?);
-                    }
-                    "update_contact" => {
-                        let contact = e
-                            .get_text()
-                            .ok_or_else(|| ParseError::missing_value("update_contact"))?;
-                        component = component.update_contact(contact.as_ref());
-                    }
-                    "project_group" => {
-                        let project_group = e
-                            .get_text()
-                            .ok_or_else(|| ParseError::missing_value("project_group"))?;
-                        component = component.project_group(project_group.as_ref());
-                    }
-                    "compulsory_for_desktop" => {
-                        let compulsory = e
-                            .get_text()
-                            .ok_or_else(|| ParseError::missing_value("compulsory_for_desktop"))?;
-                        component = component.compulsory_for_desktop(compulsory.as_ref());
-                    }
-                    "pkgname" => {
-                        let pkgname = e
-                            .get_text()
-                            .ok_or_else(|| ParseError::missing_value("pkgname"))?;
-                        component = component.pkgname(pkgname.as_ref());
-                    }
-                    "categories" => {
-                        for child in e.children.iter() {
-                            if let XMLNode::Element(element) = child {
-                                let category = element
-                                    .get_text()
-                                    .ok_or_else(|| ParseError::missing_value("category"))?
-                                    .to_string();
-                                component = component.category(
-                                    Category::from_str(&category).map_err(|_| {
-                                        ParseError::invalid_value(&category, "$value", "category")
-                                    })?,
-                                );
+    if let Some(kind) = e.attributes.get("type") {
+        component = component.kind(
+            ComponentKind::from_str(kind.as_str())
+                .map_err(|_| ParseError::invalid_value(kind, "type", "component"))?,
+        );
+    }
+
+    if let Some(merge) = e.attributes.get("merge") {
+        component = component.merge(
+            MergeKind::from_str(merge)
+                .map_err(|_| ParseError::invalid_value(merge, "merge", "component"))?,
+        );
+    }
+
+    let app_id = match e.get_child("id") {
+        Some(id) => AppId::try_from(id)?,
+        None if !require_id => AppId(String::new()),
+        None => return Err(ParseError::missing_tag("id")),
+    };
+
+    let mut name = TranslatableString::default();
+    let mut summary = TranslatableString::default();
+    let mut developer_name = TranslatableString::default();
+    let mut keywords = TranslatableList::default();
+    let mut machine_translated_keywords = TranslatableList::default();
+    let mut description = MarkupTranslatableString::default();
+    let mut categories = Vec::new();
+    for node in &e.children {
+        if let xmltree::XMLNode::Element(ref e) = node {
+            match &*e.name {
+                "name" => name.add_for_element(e),
+                "summary" => summary.add_for_element(e),
+                "developer_name" => developer_name.add_for_element(e),
+                "description" => description.add_for_element(e),
+                "project_license" => {
+                    component = component.project_license(License::try_from(e)?);
+                }
+                "metadata_license" => {
+                    component = component.metadata_license(License::try_from(e)?);
+                }
+                "icon" => {
+                    let icon = if strict {
+                        Icon::try_from_strict(e)?
+                    } else {
+                        Icon::try_from(e)?
+                    };
+                    component = component.icon(icon);
+                }
+                "update_contact" => {
+                    let contact = e
+                        .get_text()
+                        .ok_or_else(|| ParseError::missing_value("update_contact"))?;
+                    component = component.update_contact(contact.as_ref());
+                }
+                "project_group" => {
+                    let project_group = e
+                        .get_text()
+                        .ok_or_else(|| ParseError::missing_value("project_group"))?;
+                    component = component.project_group(project_group.as_ref());
+                }
+                "compulsory_for_desktop" => {
+                    let compulsory = e
+                        .get_text()
+                        .ok_or_else(|| ParseError::missing_value("compulsory_for_desktop"))?;
+                    component = component.compulsory_for_desktop(compulsory.as_ref());
+                }
+                "pkgname" => {
+                    let pkgname = e
+                        .get_text()
+                        .ok_or_else(|| ParseError::missing_value("pkgname"))?;
+                    component = component.pkgname(pkgname.as_ref());
+                }
+                // Very old appdata files use <appcategories> instead.
+                "categories" | "appcategories" => {
+                    for child in e.children.iter() {
+                        if let XMLNode::Element(element) = child {
+                            // Empty categories are skipped.
+                            let category = element.get_text().unwrap_or_default();
+                            let category = category.trim();
+                            if category.is_empty() {
+                                continue;
+                            }
+                            let category = Category::from_str(category).map_err(|_| {
+                                ParseError::invalid_value(category, "$value", "category")
+                            })?;
+                            // Duplicated categories are skipped, keeping the first-seen order.
+                            if !categories.contains(&category) {
+                                categories.push(category);
                             }
                         }
                     }
-                    "source_pkgname" => {
-                        let source_pkgname = e
-                            .get_text()
-                            .ok_or_else(|| ParseError::missing_value("source_pkgname"))?;
-                        component = component.source_pkgname(source_pkgname.as_ref());
-                    }
-                    "keywords" => {
-                        for c in e.children.iter() {
-                            if let XMLNode::Element(element) = c {
-                                keywords.add_for_element(element);
+                }
+                "source_pkgname" => {
+                    let source_pkgname = e
+                        .get_text()
+                        .ok_or_else(|| ParseError::missing_value("source_pkgname"))?;
+                    component = component.source_pkgname(source_pkgname.as_ref());
+                }
+                "keywords" => {
+                    for c in e.children.iter() {
+                        if let XMLNode::Element(element) = c {
+                            keywords.add_for_element(element);
+                            if element.attributes.get("translated").map(|t| t.as_str())
+                                == Some("yes")
+                            {
+                                machine_translated_keywords.add_for_element(element);
                             }
                         }
                     }
-                    "kudos" => {
-                        for child in e.children.iter() {
-                            if let XMLNode::Element(element) = child {
-                                let kudo = element
-                                    .get_text()
-                                    .ok_or_else(|| ParseError::missing_value("kudo"))?
-                                    .to_string();
-                                component =
-                                    component.kudo(Kudo::from_str(&kudo).map_err(|_| {
-                                        ParseError::invalid_value(&kudo, "$value", "kudo")
-                                    })?);
-                            }
+                }
+                "kudos" => {
+                    for child in e.children.iter() {
+                        if let XMLNode::Element(element) = child {
+                            let kudo = element
+                                .get_text()
+                                .ok_or_else(|| ParseError::missing_value("kudo"))?
+                                .to_string();
+                            // Unknown kudos are kept as `Kudo::Unknown`.
+                            component = component
+                                .kudo(Kudo::from_str(&kudo).unwrap_or(Kudo::Unknown(kudo)));
                         }
                     }
-                    "mimetypes" => {
-                        for child in e.children.iter() {
-                            if let XMLNode::Element(element) = child {
-                                component = component.mimetype(
-                                    &element
-                                        .get_text()
-                                        .ok_or_else(|| ParseError::missing_value("mimetype"))?,
-                                );
-                            }
+                }
+                "mimetypes" => {
+                    for child in e.children.iter() {
+                        if let XMLNode::Element(element) = child {
+                            component = component.mimetype(
+                                &element
+                                    .get_text()
+                                    .ok_or_else(|| ParseError::missing_value("mimetype"))?,
+                            );
                         }
                     }
-                    "screenshots" => {
-                        for child in e.children.iter() {
-                            if let XMLNode::Element(element) = child {
+                }
+                "screenshots" => {
+                    for child in e.children.iter() {
+                        match child {
+                            XMLNode::Element(element) if element.name == "screenshot" => {
                                 component = component.screenshot(Screenshot::try_from(element)?);
                             }
+                            _ => (),
                         }
                     }
+                }
+                // Malformed metainfo files sometimes omit the <screenshots> wrapper.
+                "screenshot" => {
+                    component = component.screenshot(Screenshot::try_from(e)?);
+                }
 
-                    "releases" => {
-                        for child in e.children.iter() {
-                            if let XMLNode::Element(element) = child {
-                                component = component.release(Release::try_from(element)?);
-                            }
-                        }
+                "releases" => {
+                    if let Some(order) = e.attributes.get("order") {
+                        component =
+                            component.release_order(ReleaseOrder::from_str(order).map_err(
+                                |_| ParseError::invalid_value(order, "order", "releases"),
+                            )?);
                     }
-                    "extends" => {
-                        component = component.extend(AppId::try_from(e)?);
-                    }
-                    "translation" => {
-                        component = component.translation(Translation::try_from(e)?);
+                    // The releases are stored in a separate file, see
+                    // `Component::load_external_releases`.
+                    if e.attributes.get("type").map(|t| t.as_str()) == Some("external") {
+                        if let Some(url) = e.attributes.get("url") {
+                            component = component.releases_url(Url::parse(url)?);
+                        }
                     }
-                    "launchable" => {
-                        component = component.launchable(Launchable::try_from(e)?);
+                    for child in e.children.iter() {
+                        if let XMLNode::Element(element) = child {
+                            component = component.release(Release::try_from(element)?);
+                        }
                     }
-                    "content_rating" => {
-                        component = component.content_rating(ContentRating::try_from(e)?);
+                }
+                "extends" => {
+                    component = component.extend(AppId::try_from(e)?);
+                }
+                "translation" => {
+                    component = component.translation(Translation::try_from(e)?);
+                }
+                "launchable" => {
+                    component = component.launchable(Launchable::try_from(e)?);
+                }
+                "content_rating" => {
+                    let content_rating = ContentRating::try_from(e)?;
+                    // Keep the most recent OARS version if multiple ones are set.
+                    if component
+                        .content_rating
+                        .as_ref()
+                        .is_none_or(|c| c.version < content_rating.version)
+                    {
+                        component = component.content_rating(content_rating);
                     }
-                    "languages" => {
-                        for child in e.children.iter() {
-                            if let XMLNode::Element(element) = child {
-                                component = component.language(Language::try_from(element)?);
-                            }
+                }
+                "branding" => {
+                    component = component.branding(Branding::try_from(e)?);
+                }
+                "languages" => {
+                    for child in e.children.iter() {
+                        if let XMLNode::Element(element) = child {
+                            component = component.language(Language::try_from(element)?);
                         }
                     }
-                    "provides" => {
-                        for child in e.children.iter() {
-                            if let XMLNode::Element(element) = child {
-                                component = component.provide(Provide::try_from(element)?);
+                }
+                "provides" => {
+                    for child in e.children.iter() {
+                        if let XMLNode::Element(element) = child {
+                            match Provide::try_from(element)? {
+                                Provide::Unknown { tag, .. } if strict => {
+                                    return Err(ParseError::unsupported(&format!(
+                                        "<{}> in <provides>",
+                                        tag
+                                    )));
+                                }
+                                Provide::Unknown { tag, value } => {
+                                    warnings.push(ValidationWarning::UnknownProvide(tag.clone()));
+                                    component = component.provide(Provide::Unknown { tag, value });
+                                }
+                                // Legacy ids that are not reverse-DNS names are only
+                                // tolerated outside of strict mode.
+                                Provide::Id(id) if strict && !id.is_reverse_dns() => {
+                                    return Err(ParseError::invalid_value(&id.0, "$value", "id"));
+                                }
+                                provide => component = component.provide(provide),
                             }
                         }
                     }
-                    "url" => {
-                        component = component.url(ProjectUrl::try_from(e)?);
-                    }
-                    "bundle" => {
-                        component = component.bundle(Bundle::try_from(e)?);
-                    }
-                    "suggests" => {
-                        for child in e.children.iter() {
-                            if let XMLNode::Element(element) = child {
-                                component = component.suggest(AppId::try_from(element)?);
-                            }
+                }
+                "url" => {
+                    component = component.url(ProjectUrl::try_from(e)?);
+                }
+                "bundle" => {
+                    component = component.bundle(Bundle::try_from(e)?);
+                }
+                "suggests" => {
+                    for child in e.children.iter() {
+                        if let XMLNode::Element(element) = child {
+                            component = component.suggest(AppId::try_from(element)?);
                         }
                     }
-                    "metadata" => {
-                        for child in &e.children {
-                            if let XMLNode::Element(element) = child {
-                                let key = element
-                                    .attributes
-                                    .get("key")
-                                    .ok_or_else(|| ParseError::missing_attribute("key", "value"))?
-                                    .to_owned();
-
+                }
+                "metadata" | "custom" => {
+                    for child in &e.children {
+                        if let XMLNode::Element(element) = child {
+                            // Skip the values without a key instead of dropping the whole
+                            // component.
+                            if let Some(key) = element.attributes.get("key") {
                                 let value = element.get_text().map(|c| c.to_string());
-                                component = component.metadata(key, value);
+                                component = component.metadata(key.to_owned(), value);
                             }
                         }
                     }
-                    "requires" => {
-                        for child in e.children.iter() {
-                            if let XMLNode::Element(element) = child {
-                                component = component.requires(Requirement::try_from(element)?);
-                            }
+                }
+                "requires" => {
+                    for child in e.children.iter() {
+                        if let XMLNode::Element(element) = child {
+                            component = component.requires(parse_requirement(element, e, strict)?);
                         }
                     }
-                    "recommends" => {
-                        for child in e.children.iter() {
-                            if let XMLNode::Element(element) = child {
-                                component = component.recommends(Requirement::try_from(element)?);
-                            }
+                }
+                "recommends" => {
+                    for child in e.children.iter() {
+                        if let XMLNode::Element(element) = child {
+                            component =
+                                component.recommends(parse_requirement(element, e, strict)?);
                         }
                     }
-                    "supports" => {
-                        for child in e.children.iter() {
-                            if let XMLNode::Element(element) = child {
-                                component = component.supports(Requirement::try_from(element)?);
-                            }
+                }
+                "supports" => {
+                    for child in e.children.iter() {
+                        if let XMLNode::Element(element) = child {
+                            component = component.supports(parse_requirement(element, e, strict)?);
                         }
                     }
-                    _ => (),
                 }
-            };
+                _ => (),
+            }
+        };
+    }
+    keywords.dedup_locale();
+    machine_translated_keywords.dedup_locale();
+    for category in categories {
+        component = component.category(category);
+    }
+    component = component
+        .name(name)
+        .summary(summary)
+        .keywords(keywords)
+        .machine_translated_keywords(machine_translated_keywords)
+        .description(description)
+        .developer_name(developer_name)
+        .id(app_id);
+    // The first screenshot is the default one when none is marked as such.
+    if !component.screenshots.iter().any(|s| s.is_default) {
+        if let Some(screenshot) = component.screenshots.first_mut() {
+            screenshot.is_default = true;
         }
-        component = component
-            .name(name)
-            .summary(summary)
-            .keywords(keywords)
-            .description(description)
-            .developer_name(developer_name)
-            .id(app_id);
-        Ok(component.build())
+    }
+    Ok((component.build(), warnings))
+}
+
+/// Parses a child of `<requires>`, `<recommends>` or `<supports>`, the
+/// requirements that are not represented yet are rejected in `strict` mode.
+fn parse_requirement(
+    e: &Element,
+    relation: &Element,
+    strict: bool,
+) -> Result<Requirement, ParseError> {
+    match Requirement::try_from(e)? {
+        Requirement::Other if strict => Err(ParseError::unsupported(&format!(
+            "<{}> in <{}>",
+            e.name, relation.name
+        ))),
+        requirement => Ok(requirement),
     }
 }
 
@@ -506,7 +719,13 @@ impl TryFrom<&Element> for Icon {
         };
 
         Ok(match kind {
-            "stock" => Icon::Stock(val),
+            "stock" => {
+                let name = val.trim();
+                if name.is_empty() {
+                    return Err(ParseError::missing_value("icon"));
+                }
+                Icon::Stock(name.to_string())
+            }
             "cached" => Icon::Cached {
                 path: val.into(),
                 width,
@@ -540,12 +759,13 @@ impl TryFrom<&Element> for Image {
         )?;
         let mut img = ImageBuilder::new(url);
 
-        let kind = match e.attributes.get("type") {
-            Some(t) => {
-                ImageKind::from_str(t).map_err(|_| ParseError::invalid_value(t, "type", "image"))?
-            }
-            None => ImageKind::Source,
-        };
+        // Unknown image types are considered to be the source image, they are
+        // reported by `Component::validate_element`.
+        let kind = e
+            .attributes
+            .get("type")
+            .and_then(|t| ImageKind::from_str(&t.to_lowercase()).ok())
+            .unwrap_or_default();
 
         img = img.kind(kind);
 
@@ -563,6 +783,13 @@ impl TryFrom<&Element> for Image {
             );
         }
 
+        if let Some(s) = e.attributes.get("scale") {
+            img = img.scale(
+                s.parse::<u32>()
+                    .map_err(|_| ParseError::invalid_value(s, "scale", "image"))?,
+            );
+        }
+
         Ok(img.build())
     }
 }
@@ -647,7 +874,8 @@ impl TryFrom<&Element> for ProjectUrl {
                 "bugtracker" => Ok(ProjectUrl::BugTracker(Url::parse(&val)?)),
                 _ => Ok(ProjectUrl::Unknown(Url::parse(&val)?)),
             },
-            None => Err(ParseError::missing_attribute("type", "url")),
+            // Sloppy metadata omits the type of the upstream homepage.
+            None => Ok(ProjectUrl::Homepage(Url::parse(&val)?)),
         }
     }
 }
@@ -662,25 +890,11 @@ impl TryFrom<&Element> for Provide {
             .into_owned();
 
         match e.name.as_ref() {
-            "mediatype" => Ok(Provide::MediaType(val)),
-            "library" => Ok(Provide::Library(val.into())),
-            "binary" => Ok(Provide::Binary(val)),
-            "font" => Ok(Provide::Font(val)),
-            "modalias" => Ok(Provide::Modalias(val)),
-            "python2" => Ok(Provide::Python2(val)),
-            "python3" => Ok(Provide::Python3(val)),
-            "dbus" => Ok(Provide::DBus(val)),
-            "id" => Ok(Provide::Id(val.into())),
-            "codec" => Ok(Provide::Codec(val)),
             "firmware" => match e.attributes.get("type") {
-                Some(kind) => {
-                    let kind = FirmwareKind::from_str(kind)
-                        .map_err(|_| ParseError::invalid_value(kind, "type", "firmware"))?;
-                    Ok(Provide::Firmware { kind, item: val })
-                }
+                Some(kind) => Provide::from_kind_value("firmware", &format!("{}:{}", kind, val)),
                 None => Err(ParseError::missing_attribute("type", "firmware")),
             },
-            t => Err(ParseError::invalid_value(t, "type", "provide")),
+            kind => Provide::from_kind_value(kind, &val),
         }
     }
 }
@@ -697,24 +911,37 @@ impl TryFrom<&Element> for Release {
 
         let mut release = ReleaseBuilder::new(&version);
 
-        let date = e.attributes.get("date").map(|d| {
-            deserialize_date(d).map_err(|_| ParseError::invalid_value(d, "date", "release"))
-        });
-        if let Some(d) = date {
-            release = release.date(d?);
-        }
+        let date = e
+            .attributes
+            .get("date")
+            .map(|d| deserialize_date(d, "date"))
+            .transpose()?;
 
-        // In case we have a timestamp attribute instead of a date one
-        let timestamp = e.attributes.get("timestamp").map(|d| {
-            deserialize_date(d).map_err(|_| ParseError::invalid_value(d, "timestamp", "release"))
-        });
-        if let Some(d) = timestamp {
-            release = release.date(d?);
+        let timestamp = e
+            .attributes
+            .get("timestamp")
+            .map(|d| deserialize_date(d, "timestamp"))
+            .transpose()?;
+
+        // The timestamp is the canonical machine-readable form and takes
+        // precedence over the date, both are expected to describe the same day.
+        #[cfg(feature = "chrono")]
+        if let (Some(date), Some(timestamp)) = (&date, &timestamp) {
+            if (*timestamp - *date).num_seconds().abs() > chrono::Duration::days(1).num_seconds() {
+                return Err(ParseError::other(
+                    "release",
+                    "the date and timestamp attributes disagree by more than a day",
+                ));
+            }
+        }
+        if let Some(d) = timestamp.or(date) {
+            release = release.date(d);
         }
 
-        let date_eol = e.attributes.get("date_eol").map(|d| {
-            deserialize_date(d).map_err(|_| ParseError::invalid_value(d, "date_eol", "release"))
-        });
+        let date_eol = e
+            .attributes
+            .get("date_eol")
+            .map(|d| deserialize_date(d, "date_eol"));
         if let Some(d) = date_eol {
             release = release.date_eol(d?);
         }
@@ -746,8 +973,22 @@ impl TryFrom<&Element> for Release {
                     "size" => {
                         release = release.size(Size::try_from(c)?);
                     }
-                    "description" => description.add_for_element(c),
-                    "url" => {
+                    "description" => {
+                        let has_markup = c.children.iter().any(|n| n.as_element().is_some());
+                        match c.get_text() {
+                            // Wrap bare text descriptions in a paragraph so the
+                            // markup is consistent with the other releases.
+                            Some(text) if !has_markup && !text.trim().is_empty() => description
+                                .add_for_locale(
+                                    c.attributes.get("lang").map(|l| l.as_str()),
+                                    &format!("<p>{}</p>", text.trim()),
+                                ),
+                            _ => description.add_for_element(c),
+                        }
+                    }
+                    // The details type is the only one defined by the specs
+                    // and is the default one, others are ignored.
+                    "url" if c.attributes.get("type").is_none_or(|t| t == "details") => {
                         release = release.url(Url::parse(
                             c.get_text()
                                 .ok_or_else(|| ParseError::missing_value("url"))?
@@ -767,13 +1008,12 @@ impl TryFrom<&Element> for Screenshot {
     type Error = ParseError;
 
     fn try_from(e: &Element) -> Result<Self, Self::Error> {
-        let mut s = ScreenshotBuilder::default().set_default(
-            e.attributes
-                .get("type")
-                .map(|t| t.as_str() == "default")
-                .unwrap_or_else(|| false),
-        );
+        // Unknown types are treated as extra screenshots, they are reported by
+        // `Component::validate_element`.
+        let is_default = e.attributes.get("type").map(|t| t.as_str()) == Some("default");
+        let mut s = ScreenshotBuilder::default().set_default(is_default);
         let mut caption = TranslatableString::default();
+        let mut videos = Vec::new();
         for node in &e.children {
             if let xmltree::XMLNode::Element(ref e) = node {
                 match &*e.name {
@@ -784,13 +1024,13 @@ impl TryFrom<&Element> for Screenshot {
                         caption.add_for_element(e);
                     }
                     "video" => {
-                        s = s.video(Video::try_from(e)?);
+                        videos.push(Video::try_from(e)?);
                     }
                     _ => (),
                 }
             }
         }
-        Ok(s.caption(caption).build())
+        Ok(s.caption(caption).videos(videos).build())
     }
 }
 
@@ -871,7 +1111,17 @@ impl TryFrom<&Element> for Video {
             );
         }
 
-        Ok(video.build())
+        let mut caption = TranslatableString::default();
+        for child in e.children.iter() {
+            match child {
+                XMLNode::Element(element) if element.name == "caption" => {
+                    caption.add_for_element(element);
+                }
+                _ => (),
+            }
+        }
+
+        Ok(video.caption(caption).build())
     }
 }
 